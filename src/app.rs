@@ -1,4 +1,5 @@
-use crate::house::{HouseGadget, register_gadgets};
+use crate::house::bus::{self, Message};
+use crate::house::{GadgetKind, HouseGadget, color_emoji, register_gadgets, scratchpad};
 use egui::Id;
 
 fn setup_custom_fonts(ctx: &egui::Context) {
@@ -57,6 +58,8 @@ impl MyApp {
         // This is also where you can customize the look and feel of egui using
         // `cc.egui_ctx.set_visuals` and `cc.egui_ctx.set_fonts`.
         setup_custom_fonts(&cc.egui_ctx);
+        #[cfg(feature = "color_emoji")]
+        egui_extras::install_image_loaders(&cc.egui_ctx);
         cc.egui_ctx.set_visuals(egui::Visuals::light());
 
         Default::default()
@@ -66,6 +69,33 @@ impl MyApp {
         self.next_id += 1;
         Id::new(self.next_id)
     }
+
+    pub fn open(&mut self, kind: GadgetKind) -> Id {
+        let id = self.new_id();
+        self.instances.push((id, true, kind.create()));
+        id
+    }
+
+    fn deliver(&mut self, ctx: &egui::Context, message: &Message) {
+        match message {
+            Message::TextProduced { text } => scratchpad::record(ctx, text),
+            Message::RequestFocus(id) => {
+                ctx.move_to_top(egui::LayerId::new(egui::Order::Middle, *id));
+            }
+            Message::OpenWithInput { kind, text } => {
+                let id = self.open(*kind);
+                let set_input = Message::SetInput {
+                    to: id,
+                    text: text.clone(),
+                };
+                self.deliver(ctx, &set_input);
+            }
+            Message::SetInput { .. } => {}
+        }
+        for (id, _, widget) in &mut self.instances {
+            widget.receive(*id, message);
+        }
+    }
 }
 
 impl eframe::App for MyApp {
@@ -83,6 +113,8 @@ impl eframe::App for MyApp {
             .min_width(160.0)
             .show(ctx, |ui| {
                 register_gadgets(self, ui);
+                ui.separator();
+                color_emoji::toggle(ui);
             });
 
         egui::CentralPanel::default().show(ctx, |ui| {
@@ -99,9 +131,23 @@ impl eframe::App for MyApp {
                 egui::warn_if_debug_build(ui);
             });
 
+            let takes_input = self
+                .instances
+                .iter_mut()
+                .filter_map(|(id, _, widget)| {
+                    widget.input()?;
+                    Some((*id, widget.title()))
+                })
+                .collect();
+            bus::set_open_gadgets(ctx, takes_input);
+
             for (id, open, widget) in &mut self.instances {
                 widget.show(id, ctx, open);
             }
+
+            for message in bus::take_all(ctx) {
+                self.deliver(ctx, &message);
+            }
         });
 
         self.instances.retain(|(_, open, _)| *open);
@@ -0,0 +1,45 @@
+//! Messages between gadgets.
+//!
+//! Gadgets only see their own `Ui`, so they publish into egui's memory while drawing,
+//! and `MyApp` delivers everything to `HouseGadget::receive` once every window is drawn.
+
+use egui::Id;
+
+use crate::house::GadgetKind;
+
+const QUEUE_ID: &str = "Message queue";
+const OPEN_GADGETS_ID: &str = "Open gadgets";
+
+#[derive(Clone)]
+pub enum Message {
+    /// A gadget produced a result worth keeping, such as a decoded answer.
+    TextProduced { text: String },
+    /// Brings a gadget's window to the front.
+    RequestFocus(Id),
+    /// Opens a new gadget with its input filled in.
+    OpenWithInput { kind: GadgetKind, text: String },
+    /// Replaces the input of an open gadget.
+    SetInput { to: Id, text: String },
+}
+
+pub fn publish(ctx: &egui::Context, message: Message) {
+    ctx.data_mut(|data| {
+        data.get_temp_mut_or_default::<Vec<Message>>(Id::new(QUEUE_ID))
+            .push(message);
+    });
+}
+
+/// Empties the queue, oldest message first.
+pub fn take_all(ctx: &egui::Context) -> Vec<Message> {
+    ctx.data_mut(|data| std::mem::take(data.get_temp_mut_or_default(Id::new(QUEUE_ID))))
+}
+
+/// Records the open gadgets that take input, with their titles.
+pub fn set_open_gadgets(ctx: &egui::Context, gadgets: Vec<(Id, String)>) {
+    ctx.data_mut(|data| data.insert_temp(Id::new(OPEN_GADGETS_ID), gadgets));
+}
+
+pub fn open_gadgets(ctx: &egui::Context) -> Vec<(Id, String)> {
+    ctx.data(|data| data.get_temp(Id::new(OPEN_GADGETS_ID)))
+        .unwrap_or_default()
+}
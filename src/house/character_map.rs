@@ -0,0 +1,365 @@
+//! All of Unicode, searched by name or browsed by block, with a click to copy.
+
+use std::sync::OnceLock;
+
+use egui::{Align2, FontId, Sense, vec2};
+use unicode_blocks::UnicodeBlock;
+
+use crate::house::bus::{self, Message};
+use crate::house::{GadgetKind, HouseGadget, scratchpad};
+
+const GRID_FONT_SIZE: f32 = 22.0;
+const GRID_CELL_SIZE: f32 = 32.0;
+const CODE_FONT_SIZE: f32 = 9.0;
+const BRAILLE_DOT_RADIUS: f32 = 2.5;
+/// Code points named per frame while the name index is built, so the first search
+/// does not freeze the window.
+const NAMES_PER_FRAME: u32 = 0x4000;
+/// One past the last code point.
+const CODE_POINT_END: u32 = char::MAX as u32 + 1;
+
+/// Blocks that come up most in puzzles, offered as shortcuts.
+const SUGGESTED_BLOCKS: [UnicodeBlock; 9] = [
+    unicode_blocks::ARROWS,
+    unicode_blocks::MATHEMATICAL_OPERATORS,
+    unicode_blocks::LETTERLIKE_SYMBOLS,
+    unicode_blocks::BOX_DRAWING,
+    unicode_blocks::BLOCK_ELEMENTS,
+    unicode_blocks::GEOMETRIC_SHAPES,
+    unicode_blocks::MISCELLANEOUS_SYMBOLS,
+    unicode_blocks::BRAILLE_PATTERNS,
+    unicode_blocks::HANGUL_COMPATIBILITY_JAMO,
+];
+
+/// Every block, in code point order.
+fn blocks() -> &'static [UnicodeBlock] {
+    static BLOCKS: OnceLock<Vec<UnicodeBlock>> = OnceLock::new();
+    BLOCKS.get_or_init(|| {
+        let mut blocks = Vec::new();
+        // Blocks start on multiples of 16, so the gaps between them can be skipped 16 at a time.
+        let mut code = 0;
+        while code <= u32::from(char::MAX) {
+            match char::from_u32(code).and_then(unicode_blocks::find_unicode_block) {
+                Some(block) => {
+                    blocks.push(block);
+                    code = block.end() + 1;
+                }
+                None => code += 16,
+            }
+        }
+        blocks
+    })
+}
+
+type Named = (char, Box<str>);
+
+fn with_name(c: char) -> Option<Named> {
+    Some((c, unicode_names2::name(c)?.to_string().into()))
+}
+
+/// Every named character with its name, read a few blocks at a time by `extend`.
+#[derive(Default)]
+struct NameIndex {
+    names: Vec<Named>,
+    /// The first code point that has not been read yet.
+    next: u32,
+}
+
+impl NameIndex {
+    fn complete(&self) -> bool {
+        self.next >= CODE_POINT_END
+    }
+
+    fn extend(&mut self) {
+        let end = (self.next + NAMES_PER_FRAME).min(CODE_POINT_END);
+        let chars = (self.next..end).filter_map(char::from_u32);
+        self.names.extend(chars.filter_map(with_name));
+        self.next = end;
+    }
+}
+
+fn named(c: char) -> bool {
+    unicode_names2::name(c).is_some()
+}
+
+fn block_chars(block: UnicodeBlock) -> impl Iterator<Item = char> {
+    (block.start()..=block.end())
+        .filter_map(char::from_u32)
+        .filter(|&c| named(c))
+}
+
+/// The character a query like `U+2800`, `2800` or `⠀` stands for, and whether that is the
+/// only sensible reading. Bare hex made only of letters, like `face`, is more likely a word.
+fn exact(query: &str) -> Option<(char, bool)> {
+    let mut chars = query.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        if !c.is_ascii_alphanumeric() {
+            return Some((c, true));
+        }
+    }
+    let prefixed = query
+        .strip_prefix("U+")
+        .or_else(|| query.strip_prefix("u+"));
+    let hex = prefixed.unwrap_or(query);
+    if hex.len() < 4 {
+        return None;
+    }
+    let c = char::from_u32(u32::from_str_radix(hex, 16).ok()?).filter(|&c| named(c))?;
+    Some((
+        c,
+        prefixed.is_some() || hex.chars().any(|c| c.is_ascii_digit()),
+    ))
+}
+
+/// Characters among `names` whose name or block has a word starting with each word of the
+/// query. Exact names come first, then names that start with the query, then code point order.
+fn search(query: &str, names: &[Named]) -> Vec<char> {
+    let exact = exact(query.trim());
+    let query = query.trim().to_uppercase();
+    let words = query.split_whitespace().collect::<Vec<_>>();
+    // The substring check is fast and rules out most names before they are split into words.
+    let has_words = |text: &str| {
+        words.iter().all(|word| {
+            text.contains(word)
+                && text
+                    .split([' ', '-'])
+                    .any(|candidate| candidate.starts_with(word))
+        })
+    };
+    let matching_blocks = blocks()
+        .iter()
+        .filter(|block| has_words(&block.name().to_uppercase()))
+        .collect::<Vec<_>>();
+    let mut found = names
+        .iter()
+        .filter(|(c, name)| {
+            has_words(name) || matching_blocks.iter().any(|block| block.contains(*c))
+        })
+        .map(|(c, name)| {
+            (
+                u8::from(**name != query) + u8::from(!name.starts_with(&query)),
+                *c,
+            )
+        })
+        .collect::<Vec<_>>();
+    found.sort_by_key(|&(rank, _)| rank);
+    let mut chars = found.into_iter().map(|(_, c)| c).collect::<Vec<_>>();
+    match exact {
+        Some((c, true)) => {
+            chars.retain(|&other| other != c);
+            chars.insert(0, c);
+        }
+        Some((c, false)) if !chars.contains(&c) => chars.push(c),
+        _ => {}
+    }
+    chars
+}
+
+fn tooltip(c: char) -> String {
+    let name = unicode_names2::name(c).map_or_else(String::new, |name| name.to_string());
+    let block = unicode_blocks::find_unicode_block(c).map_or("", |block| block.name());
+    format!("U+{:04X} {name}\n{block}", u32::from(c))
+}
+
+/// Braille cells are drawn as dots, since the fonts do not have them.
+fn paint_braille(ui: &egui::Ui, rect: egui::Rect, c: char) {
+    let dots = u32::from(c) - unicode_blocks::BRAILLE_PATTERNS.start();
+    // Dots 1-3 run down the left column, 4-6 down the right, and 7 and 8 sit below them.
+    #[rustfmt::skip]
+    let positions = [(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2), (0, 3), (1, 3)];
+    let spacing = rect.height() / 5.0;
+    for (bit, (column, row)) in positions.into_iter().enumerate() {
+        let offset = vec2(column as f32 - 0.5, row as f32 - 1.5) * spacing;
+        let center = rect.center() + offset;
+        if dots & (1 << bit) == 0 {
+            let color = ui.visuals().weak_text_color().gamma_multiply(0.3);
+            ui.painter().circle_filled(center, 1.0, color);
+        } else {
+            let color = ui.visuals().text_color();
+            ui.painter()
+                .circle_filled(center, BRAILLE_DOT_RADIUS, color);
+        }
+    }
+}
+
+pub struct CharacterMap {
+    search_string: String,
+    copied: String,
+    block: Option<UnicodeBlock>,
+    /// Characters matching the block and search, and the block, search and name index
+    /// progress they were found for.
+    matched: Vec<char>,
+    matched_for: Option<(Option<&'static str>, String, u32)>,
+    /// Names for searches outside a block, kept for later searches once read.
+    names: NameIndex,
+}
+
+impl HouseGadget for CharacterMap {
+    fn new() -> Self {
+        Self {
+            search_string: String::new(),
+            copied: String::new(),
+            block: None,
+            matched: Vec::new(),
+            matched_for: None,
+            names: NameIndex::default(),
+        }
+    }
+
+    fn title(&self) -> String {
+        "Character map".to_owned()
+    }
+
+    fn input(&mut self) -> Option<&mut String> {
+        Some(&mut self.search_string)
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Search:");
+            ui.text_edit_singleline(&mut self.search_string)
+                .on_hover_text(
+                    "Words from a character name or block, a code like U+2800, or a character",
+                );
+        });
+        self.ui_blocks(ui);
+        ui.horizontal(|ui| {
+            ui.label(format!("Click on a character to copy it {}", self.copied));
+            if !self.copied.is_empty() {
+                scratchpad::send_button(ui, &self.copied);
+            }
+        });
+        ui.separator();
+
+        self.update_matches(ui.ctx());
+        if !self.names.complete() && self.searching_all() {
+            let percent = u64::from(self.names.next) * 100 / u64::from(CODE_POINT_END);
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(format!("Reading character names… {percent}%"));
+            });
+        }
+        if self.matched.is_empty() {
+            ui.label("Search by name, like \"arrow\" or \"box drawing\", or pick a block.");
+            return;
+        }
+        ui.label(format!("{} characters", self.matched.len()));
+        self.ui_grid(ui);
+    }
+}
+
+impl CharacterMap {
+    fn ui_blocks(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Block:");
+            let selected = self.block.map_or("Any", |block| block.name());
+            egui::ComboBox::from_id_salt("Block")
+                .selected_text(selected)
+                .height(400.0)
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.block, None, "Any");
+                    for &block in blocks() {
+                        ui.selectable_value(&mut self.block, Some(block), block.name());
+                    }
+                });
+        });
+        ui.horizontal_wrapped(|ui| {
+            for block in SUGGESTED_BLOCKS {
+                ui.selectable_value(&mut self.block, Some(block), block.name());
+            }
+        });
+    }
+
+    fn searching_all(&self) -> bool {
+        self.block.is_none() && !self.search_string.trim().is_empty()
+    }
+
+    /// Searches a block by naming its characters on the spot. Searching everything reads
+    /// more of the name index each frame, with the matches so far shown until it is done.
+    fn update_matches(&mut self, ctx: &egui::Context) {
+        if self.searching_all() && !self.names.complete() {
+            self.names.extend();
+            ctx.request_repaint();
+        }
+        let key = (
+            self.block.map(|block| block.name()),
+            self.search_string.clone(),
+            self.names.next,
+        );
+        if self.matched_for.as_ref() == Some(&key) {
+            return;
+        }
+        self.matched = match self.block {
+            _ if self.search_string.trim().is_empty() => self
+                .block
+                .map_or_else(Vec::new, |block| block_chars(block).collect()),
+            Some(block) => {
+                let names = block_chars(block).filter_map(with_name).collect::<Vec<_>>();
+                search(&self.search_string, &names)
+            }
+            None => search(&self.search_string, &self.names.names),
+        };
+        self.matched_for = Some(key);
+    }
+
+    /// Only the rows in view are laid out, so scrolling through a large block stays fast.
+    fn ui_grid(&mut self, ui: &mut egui::Ui) {
+        let spacing = ui.spacing().item_spacing.x;
+        let columns = ((ui.available_width() + spacing) / (GRID_CELL_SIZE + spacing)) as usize;
+        let columns = columns.max(1);
+        let rows = self.matched.len().div_ceil(columns);
+        egui::ScrollArea::vertical().show_rows(ui, GRID_CELL_SIZE, rows, |ui, visible| {
+            for row in visible {
+                let start = row * columns;
+                let end = (start + columns).min(self.matched.len());
+                ui.horizontal(|ui| {
+                    for i in start..end {
+                        self.show_char(ui, self.matched[i]);
+                    }
+                });
+            }
+        });
+    }
+
+    fn show_char(&mut self, ui: &mut egui::Ui, c: char) {
+        let size = vec2(GRID_CELL_SIZE, GRID_CELL_SIZE);
+        let (rect, response) = ui.allocate_exact_size(size, Sense::click());
+        if response.hovered() {
+            let fill = ui.visuals().widgets.hovered.weak_bg_fill;
+            ui.painter().rect_filled(rect, 2.0, fill);
+        }
+        let font = FontId::proportional(GRID_FONT_SIZE);
+        if unicode_blocks::BRAILLE_PATTERNS.contains(c) {
+            paint_braille(ui, rect, c);
+        } else if ui.fonts(|fonts| fonts.has_glyph(&font, c)) {
+            let color = ui.visuals().text_color();
+            ui.painter()
+                .text(rect.center(), Align2::CENTER_CENTER, c, font, color);
+        } else {
+            // Without a glyph the code is shown, so the cell is not an empty box.
+            let code = format!("{:04X}", u32::from(c));
+            let font = FontId::monospace(CODE_FONT_SIZE);
+            let color = ui.visuals().weak_text_color();
+            ui.painter()
+                .text(rect.center(), Align2::CENTER_CENTER, code, font, color);
+        }
+
+        let response = response.on_hover_text(tooltip(c));
+        if response.clicked() {
+            self.copied = c.to_string();
+            ui.ctx().copy_text(self.copied.clone());
+        }
+        response.context_menu(|ui| {
+            if ui.button("Send to scratchpad").clicked() {
+                scratchpad::send(ui.ctx(), &c.to_string());
+                ui.close();
+            }
+            if ui.button("Inspect").clicked() {
+                let text = c.to_string();
+                let kind = GadgetKind::UnicodeInspector;
+                bus::publish(ui.ctx(), Message::OpenWithInput { kind, text });
+                ui.close();
+            }
+        });
+    }
+}
@@ -0,0 +1,78 @@
+//! Color emoji drawn from the Twemoji images, for the places where emojis stand on their own:
+//! buttons, icons and the picker. Text that is typed or selected keeps the monochrome font,
+//! since egui fonts have no color glyphs.
+//!
+//! The images add a lot to the binary, so they are behind the `color_emoji` feature.
+
+use egui::{Atom, Atoms, Image, RichText, vec2};
+
+const ENABLED_ID: &str = "Color emoji";
+
+/// Whether emojis are drawn in color, as chosen with `toggle`.
+pub fn enabled(ctx: &egui::Context) -> bool {
+    cfg!(feature = "color_emoji")
+        && ctx
+            .data_mut(|data| data.get_persisted(egui::Id::new(ENABLED_ID)))
+            .unwrap_or(true)
+}
+
+/// A checkbox that switches between color and monochrome emojis everywhere.
+pub fn toggle(ui: &mut egui::Ui) {
+    if !cfg!(feature = "color_emoji") {
+        return;
+    }
+    let mut color = enabled(ui.ctx());
+    if ui.checkbox(&mut color, "Color emoji").changed() {
+        ui.ctx()
+            .data_mut(|data| data.insert_persisted(egui::Id::new(ENABLED_ID), color));
+    }
+}
+
+#[cfg(feature = "color_emoji")]
+fn twemoji(emoji: &str) -> Option<Image<'static>> {
+    use twemoji_assets::png::PngTwemojiAsset;
+
+    let asset = PngTwemojiAsset::from_emoji(emoji)
+        .or_else(|| PngTwemojiAsset::from_emoji(&emoji.replace('\u{fe0f}', "")))?;
+    let bytes: &'static [u8] = asset;
+    Some(Image::from_bytes(
+        format!("bytes://twemoji/{emoji}.png"),
+        bytes,
+    ))
+}
+
+#[cfg(not(feature = "color_emoji"))]
+fn twemoji(_emoji: &str) -> Option<Image<'static>> {
+    None
+}
+
+/// The emoji as a `size` point image, if color is on and there is an image for it.
+pub fn image(ctx: &egui::Context, emoji: &str, size: f32) -> Option<Image<'static>> {
+    if !enabled(ctx) {
+        return None;
+    }
+    Some(twemoji(emoji)?.fit_to_exact_size(vec2(size, size)))
+}
+
+/// Button contents for text that starts with an emoji, like "📻 NATO" or just "📝",
+/// with the emoji drawn in color when it can be.
+pub fn atoms(ctx: &egui::Context, text: &str, size: f32) -> Atoms<'static> {
+    let (head, tail) = text.split_once(' ').unwrap_or((text, ""));
+    let Some(image) = image(ctx, head, size) else {
+        return Atoms::new(RichText::new(text).size(size));
+    };
+    let mut atoms = Atoms::new(Atom::from(image));
+    if !tail.is_empty() {
+        atoms.push_right(RichText::new(tail).size(size));
+    }
+    atoms
+}
+
+/// `atoms` at the size of button text.
+pub fn button(ui: &egui::Ui, text: &str) -> Atoms<'static> {
+    atoms(
+        ui.ctx(),
+        text,
+        ui.text_style_height(&egui::TextStyle::Button),
+    )
+}
@@ -0,0 +1,389 @@
+use egui::RichText;
+
+use crate::house::HouseGadget;
+use crate::house::puzzle_hunt_tools::english;
+use crate::house::scratchpad::result_label;
+
+const BASE64_ALPHABET: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const BASE32_ALPHABET: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const MIN_BASE: u32 = 2;
+/// Bases that rank first among equally readable decodings, in order.
+const COMMON_BASES: [u32; 4] = [10, 16, 2, 8];
+const MAX_BASE: u32 = 36;
+/// Decodings at least this printable are marked as likely.
+const LIKELY_SCORE: f32 = 0.9;
+
+/// Parses whitespace- or comma-separated numbers in `base`.
+/// Prefixes like `0x`, `U+` and `0b` are allowed where they make sense.
+pub fn parse_numbers(input: &str, base: u32) -> Option<Vec<u32>> {
+    let tokens = input
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|token| !token.is_empty())
+        .collect::<Vec<_>>();
+    if tokens.is_empty() {
+        return None;
+    }
+    tokens
+        .into_iter()
+        .map(|token| {
+            let prefixes: &[&str] = match base {
+                16 => &["0x", "U+", "u+"],
+                2 => &["0b"],
+                8 => &["0o"],
+                _ => &[],
+            };
+            let token = prefixes
+                .iter()
+                .find_map(|prefix| token.strip_prefix(prefix))
+                .unwrap_or(token);
+            u32::from_str_radix(token, base).ok()
+        })
+        .collect()
+}
+
+/// Reads a string of hex digit pairs, ignoring spaces, colons and a leading `0x`.
+pub fn decode_hex(input: &str) -> Option<Vec<u8>> {
+    let digits = input
+        .strip_prefix("0x")
+        .unwrap_or(input)
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != ':')
+        .map(|c| c.to_digit(16))
+        .collect::<Option<Vec<_>>>()?;
+    if digits.is_empty() || digits.len() % 2 != 0 {
+        return None;
+    }
+    Some(
+        digits
+            .chunks(2)
+            .map(|pair| (pair[0] * 16 + pair[1]) as u8)
+            .collect(),
+    )
+}
+
+/// Decodes base64 or base32, where each character carries `bits` bits.
+fn decode_radix(input: &str, alphabet: &str, bits: u32) -> Option<Vec<u8>> {
+    let mut buffer = 0_u32;
+    let mut buffered = 0;
+    let mut bytes = Vec::new();
+    let mut chars = 0;
+    for c in input.trim_end_matches('=').chars() {
+        if c.is_whitespace() {
+            continue;
+        }
+        let c = match (c, bits) {
+            ('-', 6) => '+',
+            ('_', 6) => '/',
+            (c, 5) => c.to_ascii_uppercase(),
+            (c, _) => c,
+        };
+        let value = alphabet.find(c)? as u32;
+        buffer = (buffer << bits) | value;
+        buffered += bits;
+        chars += 1;
+        if buffered >= 8 {
+            buffered -= 8;
+            bytes.push((buffer >> buffered) as u8);
+            buffer &= (1 << buffered) - 1;
+        }
+    }
+    (chars > 0 && buffered < bits).then_some(bytes)
+}
+
+fn encode_radix(bytes: &[u8], alphabet: &str, bits: u32, group: usize) -> String {
+    let alphabet = alphabet.as_bytes();
+    let mut buffer = 0_u32;
+    let mut buffered = 0;
+    let mut encoded = String::new();
+    for &byte in bytes {
+        buffer = (buffer << 8) | u32::from(byte);
+        buffered += 8;
+        while buffered >= bits {
+            buffered -= bits;
+            encoded.push(alphabet[(buffer >> buffered) as usize & ((1 << bits) - 1)] as char);
+        }
+        buffer &= (1 << buffered) - 1;
+    }
+    if buffered > 0 {
+        encoded
+            .push(alphabet[((buffer << (bits - buffered)) as usize) & ((1 << bits) - 1)] as char);
+    }
+    while encoded.len() % group != 0 {
+        encoded.push('=');
+    }
+    encoded
+}
+
+pub fn decode_base64(input: &str) -> Option<Vec<u8>> {
+    decode_radix(input, BASE64_ALPHABET, 6)
+}
+
+pub fn decode_base32(input: &str) -> Option<Vec<u8>> {
+    decode_radix(input, BASE32_ALPHABET, 5)
+}
+
+/// How readable `text` is, from 0 to 1. Other scripts count for half as much as ASCII.
+pub fn printable_score(text: &str) -> f32 {
+    let total = text.chars().count();
+    if total == 0 {
+        return 0.0;
+    }
+    let printable = text
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c.is_ascii_punctuation() || c == ' ' {
+                1.0
+            } else if c.is_alphanumeric() {
+                0.5
+            } else {
+                0.0
+            }
+        })
+        .sum::<f32>();
+    printable / total as f32
+}
+
+fn bytes_to_text(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes).into_owned()
+}
+
+fn code_points_to_text(numbers: &[u32]) -> Option<String> {
+    numbers.iter().map(|&n| char::from_u32(n)).collect()
+}
+
+fn a1z26(numbers: &[u32]) -> Option<String> {
+    numbers
+        .iter()
+        .map(|&n| (1..=26).contains(&n).then(|| (b'A' + n as u8 - 1) as char))
+        .collect()
+}
+
+/// Labels a list of bases, with runs like `4–36` shortened.
+fn bases_label(bases: &[u32]) -> String {
+    let mut runs: Vec<(u32, u32)> = Vec::new();
+    for &base in bases {
+        match runs.last_mut() {
+            Some((_, last)) if *last + 1 == base => *last = base,
+            _ => runs.push((base, base)),
+        }
+    }
+    let runs = runs
+        .iter()
+        .map(|&(first, last)| match last - first {
+            0 => first.to_string(),
+            1 => format!("{first}, {last}"),
+            _ => format!("{first}–{last}"),
+        })
+        .collect::<Vec<_>>();
+    let plural = if bases.len() == 1 { "" } else { "s" };
+    format!("Base{plural} {}", runs.join(", "))
+}
+
+/// Every way the input can be read, as (interpretation, result) pairs, most readable first.
+/// Numbers are read in every base their digits allow, and bases that agree are listed together.
+fn decodings(input: &str) -> Vec<(String, String)> {
+    let mut numbers: Vec<(&str, String, Vec<u32>)> = Vec::new();
+    let mut add = |kind, text: String, base| match numbers
+        .iter_mut()
+        .find(|(k, t, _)| *k == kind && *t == text)
+    {
+        Some((_, _, bases)) => bases.push(base),
+        None => numbers.push((kind, text, vec![base])),
+    };
+    for base in MIN_BASE..=MAX_BASE {
+        let Some(parsed) = parse_numbers(input, base) else {
+            continue;
+        };
+        if let Some(text) = code_points_to_text(&parsed) {
+            add("code points", text, base);
+        }
+        if let Some(text) = a1z26(&parsed) {
+            add("A1Z26", text, base);
+        }
+    }
+    // How common a base each decoding comes from, which breaks ties between equally
+    // readable ones before English-looking text does.
+    let mut decodings = numbers
+        .into_iter()
+        .map(|(kind, text, bases)| {
+            let rarity = bases
+                .iter()
+                .filter_map(|base| COMMON_BASES.iter().position(|common| common == base))
+                .min()
+                .unwrap_or(COMMON_BASES.len());
+            (format!("{} {kind}", bases_label(&bases)), text, rarity)
+        })
+        .collect::<Vec<_>>();
+    if let Some(bytes) = decode_hex(input) {
+        decodings.push(("Hex bytes".to_owned(), bytes_to_text(&bytes), 0));
+    }
+    if let Some(bytes) = decode_base64(input) {
+        decodings.push(("Base64".to_owned(), bytes_to_text(&bytes), 0));
+    }
+    if let Some(bytes) = decode_base32(input) {
+        decodings.push(("Base32".to_owned(), bytes_to_text(&bytes), 0));
+    }
+
+    let mut ranked = decodings
+        .into_iter()
+        .map(|(interpretation, text, rarity)| {
+            let score = (printable_score(&text), rarity, english::score(&text));
+            (score, (interpretation, text))
+        })
+        .collect::<Vec<_>>();
+    ranked.sort_by(|(a, _), (b, _)| {
+        (b.0.total_cmp(&a.0))
+            .then(a.1.cmp(&b.1))
+            .then(b.2.total_cmp(&a.2))
+    });
+    ranked.into_iter().map(|(_, decoding)| decoding).collect()
+}
+
+fn encodings(input: &str) -> Vec<(String, String)> {
+    let join = |f: &dyn Fn(char) -> String| input.chars().map(f).collect::<Vec<_>>().join(" ");
+    let bytes = input.as_bytes();
+    vec![
+        (
+            "Code points".to_owned(),
+            join(&|c| u32::from(c).to_string()),
+        ),
+        (
+            "Unicode".to_owned(),
+            join(&|c| format!("U+{:04X}", u32::from(c))),
+        ),
+        (
+            "UTF-8 hex".to_owned(),
+            bytes.iter().map(|b| format!("{b:02x}")).collect::<String>(),
+        ),
+        (
+            "UTF-8 binary".to_owned(),
+            bytes
+                .iter()
+                .map(|b| format!("{b:08b}"))
+                .collect::<Vec<_>>()
+                .join(" "),
+        ),
+        (
+            "Base64".to_owned(),
+            encode_radix(bytes, BASE64_ALPHABET, 6, 4),
+        ),
+        (
+            "Base32".to_owned(),
+            encode_radix(bytes, BASE32_ALPHABET, 5, 8),
+        ),
+    ]
+}
+
+fn radix_string(mut n: u32, base: u32) -> String {
+    let mut digits = Vec::new();
+    loop {
+        digits.push(char::from_digit(n % base, base).unwrap_or('?'));
+        n /= base;
+        if n == 0 {
+            break;
+        }
+    }
+    digits.iter().rev().collect()
+}
+
+pub struct NumberConverter {
+    input: String,
+}
+
+impl HouseGadget for NumberConverter {
+    fn new() -> Self {
+        Self {
+            input: String::default(),
+        }
+    }
+
+    fn title(&self) -> String {
+        "Number converter".to_owned()
+    }
+
+    fn input(&mut self) -> Option<&mut String> {
+        Some(&mut self.input)
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.label("Numbers, hex bytes, base64/base32, or any text");
+        ui.text_edit_multiline(&mut self.input);
+        if self.input.trim().is_empty() {
+            return;
+        }
+
+        let decodings = decodings(self.input.trim());
+        ui.separator();
+        ui.heading("Decoded");
+        if decodings.is_empty() {
+            ui.label("Nothing to decode");
+        }
+        results_grid(ui, "Decodings", &decodings, true);
+
+        ui.separator();
+        ui.heading("Encoded");
+        results_grid(ui, "Encodings", &encodings(&self.input), false);
+        ui.collapsing("Code points in every base", |ui| {
+            let in_bases = (MIN_BASE..=MAX_BASE)
+                .map(|base| {
+                    let digits = self
+                        .input
+                        .chars()
+                        .map(|c| radix_string(u32::from(c), base))
+                        .collect::<Vec<_>>();
+                    (format!("Base {base}"), digits.join(" "))
+                })
+                .collect::<Vec<_>>();
+            results_grid(ui, "Every base", &in_bases, false);
+        });
+    }
+}
+
+fn results_grid(ui: &mut egui::Ui, name: &str, results: &[(String, String)], mark_likely: bool) {
+    egui::Grid::new(name).striped(true).show(ui, |ui| {
+        for (interpretation, result) in results {
+            let likely = mark_likely && printable_score(result) >= LIKELY_SCORE;
+            ui.label(if likely {
+                RichText::new(format!("★ {interpretation}")).strong()
+            } else {
+                RichText::new(interpretation)
+            });
+            result_label(ui, RichText::new(result).monospace(), result);
+            ui.end_row();
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_numbers_with_prefixes() {
+        assert_eq!(parse_numbers("72, 105", 10), Some(vec![72, 105]));
+        assert_eq!(parse_numbers("0x48 U+69", 16), Some(vec![0x48, 0x69]));
+        assert_eq!(parse_numbers("0b1001000", 2), Some(vec![72]));
+        assert_eq!(parse_numbers("0o110", 8), Some(vec![72]));
+        assert_eq!(parse_numbers("12 9", 8), None);
+        assert_eq!(parse_numbers(" ", 10), None);
+    }
+
+    #[test]
+    fn decodes_bytes() {
+        assert_eq!(decode_hex("0x48:69"), Some(b"Hi".to_vec()));
+        assert_eq!(decode_hex("486"), None);
+        assert_eq!(decode_base64("SGVsbG8="), Some(b"Hello".to_vec()));
+        assert_eq!(decode_base32("JBSWY3DP"), Some(b"Hello".to_vec()));
+        assert_eq!(encode_radix(b"Hello", BASE64_ALPHABET, 6, 4), "SGVsbG8=");
+        assert_eq!(encode_radix(b"Hello", BASE32_ALPHABET, 5, 8), "JBSWY3DP");
+    }
+
+    #[test]
+    fn ranks_the_usual_base_first() {
+        let decodings = decodings("72 101 108 108 111");
+        assert_eq!(decodings[0].1, "Hello");
+        assert_eq!(decodings[0].0, "Base 10 code points");
+        assert_eq!(bases_label(&[2, 4, 5, 6, 7, 36]), "Bases 2, 4–7, 36");
+        assert_eq!(bases_label(&[16]), "Base 16");
+    }
+}
@@ -0,0 +1,510 @@
+mod search;
+mod subgroups;
+
+pub use search::keywords;
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use egui::text::LayoutJob;
+use egui::{Align, FontId, Id, Layout, TextFormat, vec2};
+use emojis::{Emoji, Group, SkinTone};
+use serde::{Deserialize, Serialize};
+
+use crate::house::bus::{self, Message};
+use crate::house::{GadgetKind, HouseGadget, color_emoji, scratchpad};
+
+const MEDIUM_FONT_SIZE: f32 = 16.0;
+const GRID_FONT_SIZE: f32 = 22.0;
+const GRID_CELL_SIZE: f32 = 32.0;
+const LIST_ROW_HEIGHT: f32 = 22.0;
+
+const SKIN_TONE_ID: &str = "Emoji skin tone";
+const SAVED_ID: &str = "Emoji picker";
+const MAX_RECENT: usize = 24;
+const SKIN_TONES: [(SkinTone, &str); 6] = [
+    (SkinTone::Default, "Default"),
+    (SkinTone::Light, "Light"),
+    (SkinTone::MediumLight, "Medium-light"),
+    (SkinTone::Medium, "Medium"),
+    (SkinTone::MediumDark, "Medium-dark"),
+    (SkinTone::Dark, "Dark"),
+];
+/// Words that start the names of gendered variants, like "man running" and "woman running".
+const GENDER_WORDS: [&str; 6] = ["person", "man", "woman", "people", "men", "women"];
+/// Names before a colon that list hair, gender or skin variants, like "woman: red hair".
+const VARIANT_PREFIXES: [&str; 6] = [
+    "person",
+    "man",
+    "woman",
+    "family",
+    "kiss",
+    "couple with heart",
+];
+
+fn group_name(group: Group) -> &'static str {
+    match group {
+        Group::SmileysAndEmotion => "Smileys & Emotion",
+        Group::PeopleAndBody => "People & Body",
+        Group::AnimalsAndNature => "Animals & Nature",
+        Group::FoodAndDrink => "Food & Drink",
+        Group::TravelAndPlaces => "Travel & Places",
+        Group::Activities => "Activities",
+        Group::Objects => "Objects",
+        Group::Symbols => "Symbols",
+        Group::Flags => "Flags",
+    }
+}
+
+fn group_icon(group: Group) -> &'static str {
+    match group {
+        Group::SmileysAndEmotion => "😀",
+        Group::PeopleAndBody => "👋",
+        Group::AnimalsAndNature => "🐻",
+        Group::FoodAndDrink => "🍔",
+        Group::TravelAndPlaces => "🚗",
+        Group::Activities => "⚽",
+        Group::Objects => "💡",
+        Group::Symbols => "❤",
+        Group::Flags => "🏁",
+    }
+}
+
+fn tooltip(emoji: &Emoji, found: &search::Match) -> String {
+    let mut tooltip = emoji.name().to_owned();
+    let shortcodes = emoji
+        .shortcodes()
+        .map(|shortcode| format!(":{shortcode}:"))
+        .collect::<Vec<_>>();
+    if !shortcodes.is_empty() {
+        tooltip = format!("{tooltip}\n{}", shortcodes.join(" "));
+    }
+    if found.field == search::Field::Keyword {
+        tooltip = format!("{tooltip}\n{}", found.text);
+    }
+    tooltip
+}
+
+/// Appends `text` after a space, with the characters at `highlight` marked.
+fn append_highlighted(
+    ui: &egui::Ui,
+    job: &mut LayoutJob,
+    text: &str,
+    highlight: &[usize],
+    format: &TextFormat,
+) {
+    let marked = TextFormat {
+        color: ui.visuals().strong_text_color(),
+        background: ui.visuals().selection.bg_fill,
+        ..format.clone()
+    };
+    job.append(" ", 0.0, format.clone());
+    for (i, c) in text.chars().enumerate() {
+        let format = if highlight.contains(&i) {
+            &marked
+        } else {
+            format
+        };
+        job.append(c.encode_utf8(&mut [0; 4]), 0.0, format.clone());
+    }
+}
+
+/// What gendered and hair variants of an emoji have in common: "running" for "man running".
+fn variant_key(name: &str) -> &str {
+    let name = match name.split_once(": ") {
+        Some((prefix, _)) if VARIANT_PREFIXES.contains(&prefix) => prefix,
+        _ => name,
+    };
+    GENDER_WORDS
+        .iter()
+        .find_map(|word| {
+            if name == *word {
+                Some("")
+            } else {
+                name.strip_prefix(word)?.strip_prefix(' ')
+            }
+        })
+        .unwrap_or(name)
+}
+
+/// Emojis by `variant_key`, for the ones that have gendered or hair variants.
+fn variants() -> &'static HashMap<&'static str, Vec<&'static Emoji>> {
+    static VARIANTS: OnceLock<HashMap<&'static str, Vec<&'static Emoji>>> = OnceLock::new();
+    VARIANTS.get_or_init(|| {
+        let mut variants = HashMap::<&'static str, Vec<&'static Emoji>>::new();
+        for emoji in emojis::iter() {
+            variants
+                .entry(variant_key(emoji.name()))
+                .or_default()
+                .push(emoji);
+        }
+        variants.retain(|_, emojis| emojis.len() > 1);
+        variants
+    })
+}
+
+/// What the picker remembers between sessions.
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct Saved {
+    /// Newest first.
+    recent: Vec<String>,
+    favorites: Vec<String>,
+    /// Whether clicks add to `composition` instead of copying.
+    composing: bool,
+    composition: String,
+}
+
+impl Saved {
+    fn toggle_favorite(&mut self, emoji: &str) {
+        if let Some(i) = self.favorites.iter().position(|favorite| favorite == emoji) {
+            self.favorites.remove(i);
+        } else {
+            self.favorites.push(emoji.to_owned());
+        }
+    }
+}
+
+enum GridRow {
+    Heading(&'static str),
+    /// A range of `EmojiPicker::matched`.
+    Emojis(std::ops::Range<usize>),
+}
+
+pub struct EmojiPicker {
+    search_string: String,
+    copied_emoji: String,
+    saved: Saved,
+    /// `None` shows every group.
+    group: Option<Group>,
+    grid: bool,
+    /// Index into `SKIN_TONES`, applied to every emoji that has skin tones.
+    skin_tone: usize,
+    /// Emojis matching the group and search, and the group and search they were found for.
+    matched: Vec<search::Match>,
+    matched_for: Option<(Option<Group>, String)>,
+}
+
+impl HouseGadget for EmojiPicker {
+    fn new() -> Self {
+        Self {
+            search_string: String::default(),
+            copied_emoji: String::default(),
+            saved: Saved::default(),
+            group: None,
+            grid: true,
+            skin_tone: 0,
+            matched: Vec::new(),
+            matched_for: None,
+        }
+    }
+
+    fn title(&self) -> String {
+        "Emoji".to_owned()
+    }
+
+    fn input(&mut self) -> Option<&mut String> {
+        Some(&mut self.search_string)
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        let tone_id = Id::new(SKIN_TONE_ID);
+        self.skin_tone = ui
+            .ctx()
+            .data_mut(|data| *data.get_persisted_mut_or_default::<usize>(tone_id))
+            .min(SKIN_TONES.len() - 1);
+        let saved_id = Id::new(SAVED_ID);
+        self.saved = ui
+            .ctx()
+            .data_mut(|data| data.get_persisted_mut_or_default::<Saved>(saved_id).clone());
+        ui.horizontal(|ui| {
+            ui.label("Filter:");
+            ui.text_edit_singleline(&mut self.search_string);
+            ui.selectable_value(&mut self.grid, true, "Grid");
+            ui.selectable_value(&mut self.grid, false, "List");
+            color_emoji::toggle(ui);
+        });
+        ui.horizontal_wrapped(|ui| {
+            ui.selectable_value(&mut self.group, None, "All");
+            for group in Group::iter() {
+                let text = color_emoji::atoms(ui.ctx(), group_icon(group), MEDIUM_FONT_SIZE);
+                ui.selectable_value(&mut self.group, Some(group), text)
+                    .on_hover_text(group_name(group));
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.label("Skin tone:");
+            for (i, (tone, name)) in SKIN_TONES.iter().enumerate() {
+                let swatch = emojis::get("✋")
+                    .and_then(|hand| hand.with_skin_tone(*tone))
+                    .map_or("✋", Emoji::as_str);
+                let text = color_emoji::atoms(ui.ctx(), swatch, MEDIUM_FONT_SIZE);
+                ui.selectable_value(&mut self.skin_tone, i, text)
+                    .on_hover_text(*name);
+            }
+        });
+        ui.ctx()
+            .data_mut(|data| data.insert_persisted(tone_id, self.skin_tone));
+        self.ui_composition(ui);
+        let favorites = self.saved.favorites.clone();
+        self.ui_strip(ui, "Favorites", &favorites);
+        let recent = self.saved.recent.clone();
+        self.ui_strip(ui, "Recent", &recent);
+        ui.separator();
+
+        self.update_matches();
+        if self.grid {
+            self.ui_grid(ui);
+        } else {
+            self.ui_list(ui);
+        }
+        ui.ctx()
+            .data_mut(|data| data.insert_persisted(saved_id, self.saved.clone()));
+    }
+}
+
+impl EmojiPicker {
+    fn ui_composition(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut self.saved.composing, "Compose")
+                .on_hover_text("Clicked emojis are added to the text instead of copied");
+            if !self.saved.composing {
+                let copy_info_text =
+                    format!("Click on the text to copy an emoji {}", self.copied_emoji);
+                ui.label(copy_info_text);
+                if !self.copied_emoji.is_empty() {
+                    scratchpad::send_button(ui, &self.copied_emoji);
+                }
+                return;
+            }
+            let text = egui::TextEdit::singleline(&mut self.saved.composition)
+                .font(FontId::proportional(MEDIUM_FONT_SIZE));
+            ui.add(text);
+            if ui.button("Copy").clicked() {
+                ui.ctx().copy_text(self.saved.composition.clone());
+            }
+            scratchpad::send_button(ui, &self.saved.composition);
+            if ui.button("Clear").clicked() {
+                self.saved.composition.clear();
+            }
+            if ui.button("Decode rebus").clicked() {
+                let text = self.saved.composition.clone();
+                let kind = GadgetKind::PuzzleHuntTools;
+                bus::publish(ui.ctx(), Message::OpenWithInput { kind, text });
+            }
+        });
+    }
+
+    /// A row of remembered emojis, which are picked like any other.
+    fn ui_strip(&mut self, ui: &mut egui::Ui, name: &str, emojis: &[String]) {
+        if emojis.is_empty() {
+            return;
+        }
+        ui.horizontal_wrapped(|ui| {
+            ui.label(name);
+            for emoji in emojis {
+                let text = color_emoji::atoms(ui.ctx(), emoji, MEDIUM_FONT_SIZE);
+                let response = ui.add(egui::Button::new(text).frame(false));
+                if response.clicked() {
+                    self.pick(ui.ctx(), emoji);
+                }
+                response.context_menu(|ui| self.ui_favorite_button(ui, emoji));
+            }
+        });
+    }
+
+    fn ui_favorite_button(&mut self, ui: &mut egui::Ui, emoji: &str) {
+        let text = if self
+            .saved
+            .favorites
+            .iter()
+            .any(|favorite| favorite == emoji)
+        {
+            "Remove from favorites"
+        } else {
+            "Add to favorites"
+        };
+        if ui.button(text).clicked() {
+            self.saved.toggle_favorite(emoji);
+            ui.close();
+        }
+    }
+
+    /// The emoji in the preferred skin tone, if it comes in skin tones.
+    fn toned(&self, emoji: &'static Emoji) -> &'static Emoji {
+        emoji
+            .with_skin_tone(SKIN_TONES[self.skin_tone].0)
+            .unwrap_or(emoji)
+    }
+
+    fn update_matches(&mut self) {
+        let key = (self.group, self.search_string.clone());
+        if self.matched_for.as_ref() == Some(&key) {
+            return;
+        }
+        self.matched = search::search(&self.search_string, self.group);
+        self.matched_for = Some(key);
+    }
+
+    /// Rows of `columns` emojis. Without a search, every subgroup starts with its heading.
+    fn grid_rows(&self, columns: usize) -> Vec<GridRow> {
+        let sections = self.search_string.trim().is_empty();
+        let mut rows = Vec::new();
+        let mut subgroup = None;
+        let mut start = 0;
+        for (i, found) in self.matched.iter().enumerate() {
+            let name = subgroups::subgroup(found.emoji);
+            let new_section = sections && subgroup != Some(name);
+            if new_section || i - start == columns {
+                if i > start {
+                    rows.push(GridRow::Emojis(start..i));
+                }
+                start = i;
+            }
+            if new_section {
+                rows.push(GridRow::Heading(name));
+                subgroup = Some(name);
+            }
+        }
+        if start < self.matched.len() {
+            rows.push(GridRow::Emojis(start..self.matched.len()));
+        }
+        rows
+    }
+
+    /// Only the rows in view are laid out, so scrolling through every emoji stays fast.
+    fn ui_grid(&mut self, ui: &mut egui::Ui) {
+        let spacing = ui.spacing().item_spacing.x;
+        let columns = ((ui.available_width() + spacing) / (GRID_CELL_SIZE + spacing)) as usize;
+        let rows = self.grid_rows(columns.max(1));
+        egui::ScrollArea::vertical().show_rows(ui, GRID_CELL_SIZE, rows.len(), |ui, visible| {
+            for row in &rows[visible] {
+                let emojis = match row {
+                    GridRow::Heading(name) => {
+                        let size = vec2(ui.available_width(), GRID_CELL_SIZE);
+                        ui.allocate_ui_with_layout(size, Layout::left_to_right(Align::Max), |ui| {
+                            ui.strong(*name);
+                        });
+                        continue;
+                    }
+                    GridRow::Emojis(emojis) => emojis.clone(),
+                };
+                ui.horizontal(|ui| {
+                    for i in emojis {
+                        let emoji = self.matched[i].emoji;
+                        let toned = self.toned(emoji).as_str();
+                        let text = color_emoji::atoms(ui.ctx(), toned, GRID_FONT_SIZE);
+                        let button = egui::Button::new(text)
+                            .frame(false)
+                            .min_size(vec2(GRID_CELL_SIZE, GRID_CELL_SIZE));
+                        let response = ui
+                            .add(button)
+                            .on_hover_text(tooltip(emoji, &self.matched[i]));
+                        self.handle_click(ui, &response, emoji);
+                    }
+                });
+            }
+        });
+    }
+
+    fn ui_list(&mut self, ui: &mut egui::Ui) {
+        let rows = self.matched.len();
+        egui::ScrollArea::vertical().show_rows(ui, LIST_ROW_HEIGHT, rows, |ui, visible| {
+            for i in visible {
+                let size = vec2(ui.available_width(), LIST_ROW_HEIGHT);
+                ui.allocate_ui_with_layout(size, Layout::left_to_right(Align::Center), |ui| {
+                    self.show_emoji(ui, i);
+                });
+            }
+        });
+    }
+
+    fn show_emoji(&mut self, ui: &mut egui::Ui, i: usize) {
+        let found = &self.matched[i];
+        let emoji = found.emoji;
+        let mut job = LayoutJob::default();
+        let format = TextFormat {
+            font_id: FontId::proportional(MEDIUM_FONT_SIZE),
+            color: ui.visuals().text_color(),
+            ..Default::default()
+        };
+        let toned = self.toned(emoji).as_str();
+        let image = color_emoji::image(ui.ctx(), toned, MEDIUM_FONT_SIZE);
+        if image.is_none() {
+            job.append(toned, 0.0, format.clone());
+        }
+        let name_highlight = match found.field {
+            search::Field::Name => found.highlight.as_slice(),
+            _ => &[],
+        };
+        append_highlighted(ui, &mut job, emoji.name(), name_highlight, &format);
+        if found.field != search::Field::Name {
+            let weak = TextFormat {
+                color: ui.visuals().weak_text_color(),
+                ..format
+            };
+            append_highlighted(ui, &mut job, found.text, &found.highlight, &weak);
+        }
+        let image = image.map(|image| ui.add(image.sense(egui::Sense::click())));
+        let mut response = ui.label(job);
+        if let Some(image) = image {
+            response |= image;
+        }
+        let response = response.on_hover_text(tooltip(emoji, found));
+        self.handle_click(ui, &response, emoji);
+    }
+
+    fn handle_click(&mut self, ui: &egui::Ui, response: &egui::Response, emoji: &'static Emoji) {
+        if response.clicked() {
+            self.pick(ui.ctx(), self.toned(emoji).as_str());
+        }
+        response.context_menu(|ui| {
+            self.ui_favorite_button(ui, self.toned(emoji).as_str());
+            if ui.button("Send to scratchpad").clicked() {
+                scratchpad::send(ui.ctx(), self.toned(emoji).as_str());
+                ui.close();
+            }
+            self.ui_variants(ui, emoji);
+        });
+    }
+
+    /// Copies the emoji, or adds it to the composition, and remembers it as recently used.
+    fn pick(&mut self, ctx: &egui::Context, emoji: &str) {
+        if self.saved.composing {
+            self.saved.composition.push_str(emoji);
+        } else {
+            emoji.clone_into(&mut self.copied_emoji);
+            ctx.copy_text(emoji.to_owned());
+        }
+        let recent = &mut self.saved.recent;
+        recent.retain(|used| used != emoji);
+        recent.insert(0, emoji.to_owned());
+        recent.truncate(MAX_RECENT);
+    }
+
+    /// Buttons that pick the emoji's other skin tones and its gendered or hair variants.
+    fn ui_variants(&mut self, ui: &mut egui::Ui, emoji: &'static Emoji) {
+        let mut rows = Vec::new();
+        if let Some(tones) = emoji.skin_tones() {
+            rows.push(("Skin tones", tones.collect::<Vec<_>>()));
+        }
+        if let Some(others) = variants().get(variant_key(emoji.name())) {
+            let others = others.iter().map(|&other| self.toned(other)).collect();
+            rows.push(("Variants", others));
+        }
+        for (heading, row) in rows {
+            ui.separator();
+            ui.label(heading);
+            ui.horizontal_wrapped(|ui| {
+                for variant in row {
+                    let text = color_emoji::atoms(ui.ctx(), variant.as_str(), GRID_FONT_SIZE);
+                    let button = ui.button(text).on_hover_text(variant.name());
+                    if button.clicked() {
+                        self.pick(ui.ctx(), variant.as_str());
+                        ui.close();
+                    }
+                }
+            });
+        }
+    }
+}
@@ -0,0 +1,181 @@
+//! Ranked, case-insensitive emoji search over names, shortcodes and CLDR keywords.
+//!
+//! `assets/emoji_keywords.tsv` has one emoji per line: the emoji, then its English and
+//! Korean keywords, each list separated by `|`.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use emojis::{Emoji, Group};
+
+const KEYWORDS: &str = include_str!("../../../assets/emoji_keywords.tsv");
+/// Shorter queries must appear as written; longer ones may skip letters.
+const MIN_FUZZY_LEN: usize = 3;
+/// A fuzzy match may spread over at most this many times the query's length.
+const MAX_FUZZY_SPREAD: usize = 2;
+
+/// How well a query matched, best first.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Quality {
+    Exact,
+    Prefix,
+    WordPrefix,
+    Contains,
+    /// The letters of the query in order; the cost grows with skipped letters.
+    Fuzzy(usize),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Field {
+    Name,
+    Shortcode,
+    Keyword,
+}
+
+pub struct Match {
+    pub emoji: &'static Emoji,
+    pub field: Field,
+    /// The text that matched.
+    pub text: &'static str,
+    /// Character positions in `text` to highlight.
+    pub highlight: Vec<usize>,
+    quality: Quality,
+}
+
+/// An emoji and what it can be found by, with each text folded by `fold`.
+type Entry = (&'static Emoji, Vec<(Field, &'static str, Vec<char>)>);
+
+/// Lowercases one character at a time, so positions line up with the original text.
+fn fold(text: &str) -> Vec<char> {
+    text.chars()
+        .map(|c| c.to_lowercase().next().unwrap_or(c))
+        .collect()
+}
+
+/// Emoji variation selectors differ between data sets, so keys leave them out.
+fn key(emoji: &str) -> String {
+    emoji.replace('\u{fe0f}', "")
+}
+
+/// Every searchable text of every emoji, in `emojis::iter` order.
+fn index() -> &'static Vec<Entry> {
+    static INDEX: OnceLock<Vec<Entry>> = OnceLock::new();
+    INDEX.get_or_init(|| {
+        let mut keywords = HashMap::new();
+        for line in KEYWORDS.lines() {
+            let mut columns = line.split('\t');
+            if let Some(emoji) = columns.next() {
+                let words = columns.flat_map(|column| column.split('|'));
+                keywords.insert(key(emoji), words.filter(|word| !word.is_empty()).collect());
+            }
+        }
+        emojis::iter()
+            .map(|emoji| {
+                let mut fields = vec![(Field::Name, emoji.name())];
+                fields.extend(emoji.shortcodes().map(|code| (Field::Shortcode, code)));
+                if let Some(words) = keywords.get(&key(emoji.as_str())) {
+                    let words: &Vec<&'static str> = words;
+                    fields.extend(words.iter().map(|&word| (Field::Keyword, word)));
+                }
+                let fields = fields
+                    .into_iter()
+                    .map(|(field, text)| (field, text, fold(text)))
+                    .collect();
+                (emoji, fields)
+            })
+            .collect()
+    })
+}
+
+fn find(text: &[char], query: &[char]) -> Option<(Quality, Vec<usize>)> {
+    let len = query.len();
+    if text == query {
+        return Some((Quality::Exact, (0..len).collect()));
+    }
+    let mut contains = None;
+    for start in (0..text.len()).filter(|&i| text[i..].starts_with(query)) {
+        let quality = if start == 0 {
+            Quality::Prefix
+        } else if !text[start - 1].is_alphanumeric() {
+            Quality::WordPrefix
+        } else {
+            Quality::Contains
+        };
+        if quality != Quality::Contains {
+            return Some((quality, (start..start + len).collect()));
+        }
+        contains.get_or_insert((quality, (start..start + len).collect()));
+    }
+    if contains.is_some() || len < MIN_FUZZY_LEN {
+        return contains;
+    }
+
+    let mut positions = Vec::with_capacity(len);
+    let mut rest = query.iter().peekable();
+    for (i, c) in text.iter().enumerate() {
+        if rest.next_if_eq(&c).is_some() {
+            positions.push(i);
+        }
+    }
+    if rest.peek().is_some() {
+        return None;
+    }
+    let (&first, &last) = (positions.first()?, positions.last()?);
+    let spread = last - first + 1;
+    // Matches that start a word read more like what was meant.
+    let mid_word = first > 0 && text[first - 1].is_alphanumeric();
+    let cost = spread - len + if mid_word { len } else { 0 };
+    (spread <= len * MAX_FUZZY_SPREAD).then_some((Quality::Fuzzy(cost), positions))
+}
+
+/// Emojis matching the query, best first: whole matches, then prefixes,
+/// then word starts, then anywhere, then with letters in between.
+pub fn search(query: &str, group: Option<Group>) -> Vec<Match> {
+    let query = fold(query.trim());
+    let mut matches = index()
+        .iter()
+        .filter(|(emoji, _)| group.is_none_or(|group| emoji.group() == group))
+        .filter_map(|(emoji, fields)| {
+            if query.is_empty() {
+                let (field, text, _) = fields[0];
+                return Some(Match {
+                    emoji,
+                    field,
+                    text,
+                    highlight: Vec::new(),
+                    quality: Quality::Exact,
+                });
+            }
+            fields
+                .iter()
+                .filter_map(|(field, text, folded)| {
+                    let (quality, highlight) = find(folded, &query)?;
+                    Some(Match {
+                        emoji,
+                        field: *field,
+                        text,
+                        highlight,
+                        quality,
+                    })
+                })
+                .min_by_key(|found| (found.quality, found.field))
+        })
+        .collect::<Vec<_>>();
+    matches.sort_by_key(|found| (found.quality, found.field));
+    matches
+}
+
+/// The emoji's CLDR keywords, English then Korean, leaving out its name.
+pub fn keywords(emoji: &Emoji) -> Vec<&'static str> {
+    index()
+        .iter()
+        .find(|(other, _)| other.as_str() == emoji.as_str())
+        .map(|(_, fields)| {
+            fields
+                .iter()
+                .filter(|(field, text, _)| *field == Field::Keyword && *text != emoji.name())
+                .map(|&(_, text, _)| text)
+                .collect()
+        })
+        .unwrap_or_default()
+}
@@ -0,0 +1,130 @@
+//! Subgroups within each emoji group, like "Face smiling" or "Animal bird", from the
+//! [Unicode CLDR](https://cldr.unicode.org/) as packaged by the emoji crate.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use emojis::Emoji;
+
+/// The first emoji of each subgroup, in the order `emojis::iter` lists them.
+/// Emojis newer than the table join the subgroup before them.
+const SUBGROUP_STARTS: [(&str, &str); 97] = [
+    ("😀", "Face smiling"),
+    ("🥰", "Face affection"),
+    ("😋", "Face tongue"),
+    ("🤗", "Face hand"),
+    ("🤐", "Face neutral & skeptical"),
+    ("😌", "Face sleepy"),
+    ("😷", "Face unwell"),
+    ("🤠", "Face hat"),
+    ("😎", "Face glasses"),
+    ("😕", "Face concerned"),
+    ("😤", "Face negative"),
+    ("💩", "Face costume"),
+    ("😺", "Cat face"),
+    ("🙈", "Monkey face"),
+    ("💌", "Emotion"),
+    ("👋", "Hand fingers open"),
+    ("👌", "Hand fingers partial"),
+    ("👈", "Hand single finger"),
+    ("👍", "Hand fingers closed"),
+    ("👏", "Hands"),
+    ("✍\u{fe0f}", "Hand with prop"),
+    ("💪", "Body parts"),
+    ("👶", "Person"),
+    ("🙍", "Person gesture"),
+    ("🧑\u{200d}⚕\u{fe0f}", "Person role"),
+    ("👼", "Person fantasy"),
+    ("💆", "Person activity"),
+    ("🤺", "Person sport"),
+    ("🧘", "Person resting"),
+    ("🧑\u{200d}🤝\u{200d}🧑", "Family"),
+    ("🗣\u{fe0f}", "Person symbol"),
+    ("🐵", "Animal mammal"),
+    ("🦃", "Animal bird"),
+    ("🐸", "Animal amphibian"),
+    ("🐊", "Animal reptile"),
+    ("🐳", "Animal marine"),
+    ("🐌", "Animal bug"),
+    ("💐", "Plant flower"),
+    ("🌱", "Plant other"),
+    ("🍇", "Food fruit"),
+    ("🥑", "Food vegetable"),
+    ("🍞", "Food prepared"),
+    ("🍱", "Food asian"),
+    ("🍦", "Food sweet"),
+    ("🍼", "Drink"),
+    ("🥢", "Dishware"),
+    ("🌍", "Place map"),
+    ("🏔\u{fe0f}", "Place geographic"),
+    ("🏟\u{fe0f}", "Place building"),
+    ("⛪", "Place religious"),
+    ("⛲", "Place other"),
+    ("🚂", "Transport ground"),
+    ("⚓", "Transport water"),
+    ("✈\u{fe0f}", "Transport air"),
+    ("🛎\u{fe0f}", "Hotel"),
+    ("⌛", "Time"),
+    ("🌑", "Sky & weather"),
+    ("🎃", "Event"),
+    ("🎖\u{fe0f}", "Award medal"),
+    ("⚽", "Sport"),
+    ("🎯", "Game"),
+    ("🎭", "Arts & crafts"),
+    ("👓", "Clothing"),
+    ("🔇", "Sound"),
+    ("🎼", "Music"),
+    ("🎷", "Musical instrument"),
+    ("📱", "Phone"),
+    ("🔋", "Computer"),
+    ("🎥", "Light & video"),
+    ("📔", "Book & paper"),
+    ("💰", "Money"),
+    ("✉\u{fe0f}", "Mail"),
+    ("✏\u{fe0f}", "Writing"),
+    ("💼", "Office"),
+    ("🔒", "Lock"),
+    ("🔨", "Tool"),
+    ("⚗\u{fe0f}", "Science"),
+    ("💉", "Medical"),
+    ("🚪", "Household"),
+    ("🚬", "Other object"),
+    ("🏧", "Transport sign"),
+    ("⚠\u{fe0f}", "Warning"),
+    ("⬆\u{fe0f}", "Arrow"),
+    ("🛐", "Religion"),
+    ("♈", "Zodiac"),
+    ("🔀", "AV symbol"),
+    ("♀\u{fe0f}", "Gender"),
+    ("✖\u{fe0f}", "Math"),
+    ("‼\u{fe0f}", "Punctuation"),
+    ("💱", "Currency"),
+    ("⚕\u{fe0f}", "Other symbol"),
+    ("#\u{fe0f}\u{20e3}", "Keycap"),
+    ("🔠", "Alphanumeric"),
+    ("🔴", "Geometric"),
+    ("🏁", "Flag"),
+    ("🇦🇨", "Country flag"),
+    (
+        "🏴\u{e0067}\u{e0062}\u{e0065}\u{e006e}\u{e0067}\u{e007f}",
+        "Subdivision flag",
+    ),
+];
+
+/// The subgroup the emoji is listed under.
+pub fn subgroup(emoji: &Emoji) -> &'static str {
+    static SUBGROUPS: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+    let subgroups = SUBGROUPS.get_or_init(|| {
+        let starts = HashMap::from(SUBGROUP_STARTS);
+        let mut current = "";
+        emojis::iter()
+            .map(|emoji| {
+                if let Some(name) = starts.get(emoji.as_str()) {
+                    current = name;
+                }
+                (emoji.as_str(), current)
+            })
+            .collect()
+    });
+    subgroups.get(emoji.as_str()).copied().unwrap_or_default()
+}
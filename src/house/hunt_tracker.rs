@@ -0,0 +1,574 @@
+//! Tracks a hunt's rounds, puzzles and answers, and which answers feed which metas.
+//!
+//! The hunt lives in egui's persisted memory, so it is saved with the rest of the app state
+//! and every tracker window shows the same hunt.
+
+use egui::{Color32, RichText};
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator as _;
+use strum_macros::EnumIter;
+
+use crate::house::{HouseGadget, color_emoji};
+
+const HUNT_ID: &str = "Hunt tracker";
+const CSV_HEADER: [&str; 8] = [
+    "round", "name", "status", "answer", "meta", "feeds", "tags", "notes",
+];
+
+const CSV_COLUMNS_ERR: &str =
+    "Each CSV row needs 8 columns: round, name, status, answer, meta, feeds, tags, notes";
+const CSV_QUOTE_ERR: &str = "A quoted CSV field is never closed";
+const CSV_STATUS_ERR: &str = "The status should be unopened, working, solved or backsolved";
+const JSON_ERR: &str = "This is not a hunt exported as JSON";
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, EnumIter)]
+#[serde(rename_all = "lowercase")]
+enum Status {
+    #[default]
+    Unopened,
+    Working,
+    Solved,
+    Backsolved,
+}
+
+impl Status {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Unopened => "Unopened",
+            Self::Working => "Working",
+            Self::Solved => "Solved",
+            Self::Backsolved => "Backsolved",
+        }
+    }
+
+    fn color(self) -> Color32 {
+        match self {
+            Self::Unopened => Color32::GRAY,
+            Self::Working => Color32::from_rgb(200, 130, 0),
+            Self::Solved => Color32::from_rgb(30, 140, 60),
+            Self::Backsolved => Color32::from_rgb(40, 100, 180),
+        }
+    }
+
+    fn is_solved(self) -> bool {
+        matches!(self, Self::Solved | Self::Backsolved)
+    }
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct Puzzle {
+    /// Stays the same when the puzzle is renamed or others are removed, unlike its name or
+    /// place in the list. Puzzles without one get one when the hunt is shown.
+    id: u64,
+    name: String,
+    round: String,
+    status: Status,
+    answer: String,
+    is_meta: bool,
+    /// Names of the metas this puzzle's answer is used in.
+    feeds: Vec<String>,
+    tags: Vec<String>,
+    notes: String,
+}
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct Hunt {
+    puzzles: Vec<Puzzle>,
+}
+
+impl Hunt {
+    /// Gives an id to every puzzle that doesn't have one yet, like newly imported ones.
+    fn assign_ids(&mut self) {
+        let mut next = self
+            .puzzles
+            .iter()
+            .map(|puzzle| puzzle.id)
+            .max()
+            .unwrap_or(0)
+            + 1;
+        for puzzle in self.puzzles.iter_mut().filter(|puzzle| puzzle.id == 0) {
+            puzzle.id = next;
+            next += 1;
+        }
+    }
+
+    fn position(&self, id: u64) -> Option<usize> {
+        self.puzzles.iter().position(|puzzle| puzzle.id == id)
+    }
+
+    /// Round names in the order they first appear.
+    fn rounds(&self) -> Vec<String> {
+        let mut rounds = Vec::<String>::new();
+        for puzzle in &self.puzzles {
+            if !rounds.contains(&puzzle.round) {
+                rounds.push(puzzle.round.clone());
+            }
+        }
+        rounds
+    }
+
+    fn metas(&self) -> Vec<String> {
+        self.puzzles
+            .iter()
+            .filter(|puzzle| puzzle.is_meta)
+            .map(|puzzle| puzzle.name.clone())
+            .collect()
+    }
+
+    /// Points feeders of the meta `from` at `to` instead, or at nothing if `to` is `None`.
+    fn rename_meta(&mut self, from: &str, to: Option<&str>) {
+        for puzzle in &mut self.puzzles {
+            match to {
+                Some(to) => {
+                    for meta in puzzle.feeds.iter_mut().filter(|meta| *meta == from) {
+                        to.clone_into(meta);
+                    }
+                }
+                None => puzzle.feeds.retain(|meta| meta != from),
+            }
+        }
+    }
+
+    fn to_csv(&self) -> String {
+        let mut csv = CSV_HEADER.join(",");
+        csv.push('\n');
+        for puzzle in &self.puzzles {
+            let fields = [
+                puzzle.round.clone(),
+                puzzle.name.clone(),
+                puzzle.status.name().to_owned(),
+                puzzle.answer.clone(),
+                if puzzle.is_meta { "yes" } else { "" }.to_owned(),
+                puzzle.feeds.join("; "),
+                puzzle.tags.join("; "),
+                puzzle.notes.clone(),
+            ];
+            let fields = fields
+                .iter()
+                .map(|field| csv_field(field))
+                .collect::<Vec<_>>();
+            csv.push_str(&fields.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+
+    fn from_csv(text: &str) -> Result<Self, &'static str> {
+        let mut rows = parse_csv(text)?;
+        rows.retain(|row| row.iter().any(|field| !field.trim().is_empty()));
+        let has_header = rows.first().is_some_and(|row| {
+            row.iter()
+                .zip(CSV_HEADER)
+                .all(|(field, name)| field.trim().eq_ignore_ascii_case(name))
+        });
+        let list = |field: &str| {
+            field
+                .split(';')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(str::to_owned)
+                .collect()
+        };
+        let puzzles = rows
+            .iter()
+            .skip(usize::from(has_header))
+            .map(|row| {
+                let [round, name, status, answer, is_meta, feeds, tags, notes] = row.as_slice()
+                else {
+                    return Err(CSV_COLUMNS_ERR);
+                };
+                let status = if status.trim().is_empty() {
+                    Status::Unopened
+                } else {
+                    Status::iter()
+                        .find(|s| s.name().eq_ignore_ascii_case(status.trim()))
+                        .ok_or(CSV_STATUS_ERR)?
+                };
+                Ok(Puzzle {
+                    name: name.trim().to_owned(),
+                    round: round.trim().to_owned(),
+                    status,
+                    answer: answer.trim().to_owned(),
+                    is_meta: ["yes", "y", "true", "1", "x"]
+                        .iter()
+                        .any(|yes| is_meta.trim().eq_ignore_ascii_case(yes)),
+                    feeds: list(feeds),
+                    tags: list(tags),
+                    notes: notes.clone(),
+                    ..Default::default()
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { puzzles })
+    }
+}
+
+/// Quotes a CSV field if it has commas, quotes or line breaks.
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_owned()
+    }
+}
+
+/// Splits CSV into rows of fields, allowing quoted fields with commas, `""` and line breaks.
+fn parse_csv(text: &str) -> Result<Vec<Vec<String>>, &'static str> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (quoted, c) {
+            (true, '"') if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            (true, '"') => quoted = false,
+            (false, '"') if field.is_empty() => quoted = true,
+            (false, ',') => row.push(std::mem::take(&mut field)),
+            (false, '\n') => {
+                row.push(std::mem::take(&mut field));
+                rows.push(std::mem::take(&mut row));
+            }
+            (false, '\r') => {}
+            _ => field.push(c),
+        }
+    }
+    if quoted {
+        return Err(CSV_QUOTE_ERR);
+    }
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+fn status_label(ui: &mut egui::Ui, status: Status) {
+    ui.label(RichText::new(status.name()).color(status.color()));
+}
+
+fn answer_label(ui: &mut egui::Ui, answer: &str) {
+    ui.label(RichText::new(answer.to_uppercase()).monospace().strong());
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum View {
+    Puzzles,
+    Metas,
+    Transfer,
+}
+
+pub struct HuntTracker {
+    view: View,
+    filter: String,
+    /// The id of the selected puzzle.
+    selected: Option<u64>,
+    new_tag: String,
+    /// Text to import, or the last export.
+    transfer: String,
+    transfer_err: Option<&'static str>,
+    /// An answer fed in from the scratchpad, waiting for a puzzle to be selected.
+    incoming_answer: String,
+}
+
+impl HuntTracker {
+    fn ui_puzzles(&mut self, ui: &mut egui::Ui, hunt: &mut Hunt) {
+        ui.horizontal(|ui| {
+            if ui
+                .button(color_emoji::button(ui, "➕ Add puzzle"))
+                .clicked()
+            {
+                let round = hunt
+                    .puzzles
+                    .last()
+                    .map(|puzzle| puzzle.round.clone())
+                    .unwrap_or_default();
+                hunt.puzzles.push(Puzzle {
+                    name: format!("Puzzle {}", hunt.puzzles.len() + 1),
+                    round,
+                    ..Default::default()
+                });
+                hunt.assign_ids();
+                self.selected = hunt.puzzles.last().map(|puzzle| puzzle.id);
+            }
+            ui.label("Filter");
+            ui.text_edit_singleline(&mut self.filter)
+                .on_hover_text("Name, answer or tag");
+        });
+
+        let filter = self.filter.to_lowercase();
+        let shown = |puzzle: &Puzzle| {
+            filter.is_empty()
+                || puzzle.name.to_lowercase().contains(&filter)
+                || puzzle.answer.to_lowercase().contains(&filter)
+                || puzzle.tags.iter().any(|tag| tag.to_lowercase() == filter)
+        };
+        for round in hunt.rounds() {
+            let in_round = hunt
+                .puzzles
+                .iter()
+                .filter(|puzzle| puzzle.round == round)
+                .collect::<Vec<_>>();
+            let solved = in_round.iter().filter(|p| p.status.is_solved()).count();
+            let name = if round.is_empty() { "No round" } else { &round };
+            let header = format!("{name} ({solved}/{} solved)", in_round.len());
+            egui::CollapsingHeader::new(header)
+                .id_salt(("Round", &round))
+                .default_open(true)
+                .show(ui, |ui| {
+                    egui::Grid::new(("Puzzles", &round))
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for puzzle in in_round.into_iter().filter(|p| shown(p)) {
+                                let label = if puzzle.is_meta {
+                                    RichText::new(&puzzle.name).strong()
+                                } else {
+                                    RichText::new(&puzzle.name)
+                                };
+                                if ui
+                                    .selectable_label(self.selected == Some(puzzle.id), label)
+                                    .clicked()
+                                {
+                                    self.selected = Some(puzzle.id);
+                                }
+                                status_label(ui, puzzle.status);
+                                answer_label(ui, &puzzle.answer);
+                                ui.weak(puzzle.tags.join(", "));
+                                ui.end_row();
+                            }
+                        });
+                });
+        }
+
+        if let Some(index) = self.selected.and_then(|id| hunt.position(id)) {
+            ui.separator();
+            self.ui_editor(ui, hunt, index);
+        }
+    }
+
+    fn ui_editor(&mut self, ui: &mut egui::Ui, hunt: &mut Hunt, index: usize) {
+        let metas = hunt.metas();
+        let old_name = hunt.puzzles[index].name.clone();
+        let was_meta = hunt.puzzles[index].is_meta;
+        let puzzle = &mut hunt.puzzles[index];
+        egui::Grid::new("Puzzle editor").show(ui, |ui| {
+            ui.label("Name");
+            ui.text_edit_singleline(&mut puzzle.name);
+            ui.end_row();
+            ui.label("Round");
+            ui.text_edit_singleline(&mut puzzle.round);
+            ui.end_row();
+            ui.label("Status");
+            ui.horizontal(|ui| {
+                for status in Status::iter() {
+                    ui.selectable_value(&mut puzzle.status, status, status.name());
+                }
+            });
+            ui.end_row();
+            ui.label("Answer");
+            ui.text_edit_singleline(&mut puzzle.answer);
+            ui.end_row();
+            ui.label("Meta");
+            ui.checkbox(&mut puzzle.is_meta, "This puzzle is a meta");
+            ui.end_row();
+            ui.label("Feeds");
+            ui.horizontal_wrapped(|ui| {
+                for meta in metas.iter().filter(|&meta| *meta != old_name) {
+                    let mut feeds = puzzle.feeds.contains(meta);
+                    if ui.checkbox(&mut feeds, meta).changed() {
+                        if feeds {
+                            puzzle.feeds.push(meta.clone());
+                        } else {
+                            puzzle.feeds.retain(|m| m != meta);
+                        }
+                    }
+                }
+            });
+            ui.end_row();
+            ui.label("Tags");
+            ui.horizontal_wrapped(|ui| {
+                puzzle
+                    .tags
+                    .retain(|tag| !ui.button(format!("{tag} ✖")).clicked());
+                let response = ui.text_edit_singleline(&mut self.new_tag);
+                if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                    let tag = self.new_tag.trim();
+                    if !tag.is_empty() && !puzzle.tags.iter().any(|t| t == tag) {
+                        puzzle.tags.push(tag.to_owned());
+                    }
+                    self.new_tag.clear();
+                    response.request_focus();
+                }
+            });
+            ui.end_row();
+            ui.label("Notes");
+            ui.text_edit_multiline(&mut puzzle.notes);
+            ui.end_row();
+        });
+
+        let (is_meta, name) = (puzzle.is_meta, puzzle.name.clone());
+        if ui
+            .button(color_emoji::button(ui, "🗑 Delete puzzle"))
+            .clicked()
+        {
+            hunt.puzzles.remove(index);
+            self.selected = None;
+            if was_meta {
+                hunt.rename_meta(&old_name, None);
+            }
+        } else if was_meta && !is_meta {
+            hunt.rename_meta(&old_name, None);
+        } else if was_meta && name != old_name {
+            hunt.rename_meta(&old_name, Some(&name));
+        }
+    }
+
+    fn ui_transfer(&mut self, ui: &mut egui::Ui, hunt: &mut Hunt) {
+        ui.horizontal(|ui| {
+            if ui.button("Export JSON").clicked() {
+                self.transfer = serde_json::to_string_pretty(hunt).unwrap_or_default();
+                ui.ctx().copy_text(self.transfer.clone());
+                self.transfer_err = None;
+            }
+            if ui.button("Export CSV").clicked() {
+                self.transfer = hunt.to_csv();
+                ui.ctx().copy_text(self.transfer.clone());
+                self.transfer_err = None;
+            }
+            if ui.button("Import JSON").clicked() {
+                match serde_json::from_str(&self.transfer) {
+                    Ok(imported) => {
+                        *hunt = imported;
+                        self.transfer_err = None;
+                    }
+                    Err(_) => self.transfer_err = Some(JSON_ERR),
+                }
+            }
+            if ui.button("Import CSV").clicked() {
+                match Hunt::from_csv(&self.transfer) {
+                    Ok(imported) => {
+                        *hunt = imported;
+                        self.transfer_err = None;
+                    }
+                    Err(err) => self.transfer_err = Some(err),
+                }
+            }
+        });
+        ui.label("Exports are also copied to the clipboard. Importing replaces the current hunt.");
+        if let Some(err) = self.transfer_err {
+            ui.label(RichText::new(format!("⚠ {err}")).color(ui.visuals().warn_fg_color));
+        }
+        ui.add(
+            egui::TextEdit::multiline(&mut self.transfer)
+                .code_editor()
+                .desired_rows(12)
+                .desired_width(f32::INFINITY),
+        );
+    }
+}
+
+fn ui_metas(ui: &mut egui::Ui, hunt: &Hunt) {
+    let metas = hunt.puzzles.iter().filter(|puzzle| puzzle.is_meta);
+    for meta in metas {
+        let feeders = hunt
+            .puzzles
+            .iter()
+            .filter(|puzzle| puzzle.feeds.contains(&meta.name))
+            .collect::<Vec<_>>();
+        let answered = feeders.iter().filter(|p| !p.answer.is_empty()).count();
+        ui.horizontal(|ui| {
+            ui.heading(&meta.name);
+            status_label(ui, meta.status);
+            answer_label(ui, &meta.answer);
+        });
+        ui.label(format!("{answered}/{} answers known", feeders.len()));
+        egui::Grid::new(("Feeders", &meta.name))
+            .striped(true)
+            .show(ui, |ui| {
+                for feeder in feeders {
+                    ui.label(&feeder.name);
+                    status_label(ui, feeder.status);
+                    answer_label(ui, &feeder.answer);
+                    ui.end_row();
+                }
+            });
+        ui.separator();
+    }
+
+    let loose = hunt
+        .puzzles
+        .iter()
+        .filter(|puzzle| !puzzle.is_meta && puzzle.feeds.is_empty())
+        .map(|puzzle| puzzle.name.as_str())
+        .collect::<Vec<_>>();
+    if hunt.metas().is_empty() {
+        ui.label("Mark a puzzle as a meta to see its feeders here.");
+    } else if !loose.is_empty() {
+        ui.label(format!("Not feeding any meta: {}", loose.join(", ")));
+    }
+}
+
+impl HouseGadget for HuntTracker {
+    fn new() -> Self {
+        Self {
+            view: View::Puzzles,
+            filter: String::new(),
+            selected: None,
+            new_tag: String::new(),
+            transfer: String::new(),
+            transfer_err: None,
+            incoming_answer: String::new(),
+        }
+    }
+
+    fn title(&self) -> String {
+        "Hunt tracker".to_owned()
+    }
+
+    fn input(&mut self) -> Option<&mut String> {
+        Some(&mut self.incoming_answer)
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        let id = egui::Id::new(HUNT_ID);
+        let mut hunt = ui
+            .ctx()
+            .data_mut(|data| data.get_persisted_mut_or_default::<Hunt>(id).clone());
+        hunt.assign_ids();
+
+        let solved = hunt.puzzles.iter().filter(|p| p.status.is_solved()).count();
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut self.view, View::Puzzles, "Puzzles");
+            ui.selectable_value(&mut self.view, View::Metas, "Metas");
+            ui.selectable_value(&mut self.view, View::Transfer, "Import / export");
+            ui.label(format!("{solved}/{} solved", hunt.puzzles.len()));
+        });
+        ui.separator();
+
+        if !self.incoming_answer.is_empty() {
+            self.view = View::Puzzles;
+            let selected = self.selected.and_then(|id| hunt.position(id));
+            if let Some(puzzle) = selected.map(|i| &mut hunt.puzzles[i]) {
+                puzzle.answer = std::mem::take(&mut self.incoming_answer).trim().to_owned();
+            } else {
+                ui.label(format!(
+                    "Select a puzzle to give it the answer {}",
+                    self.incoming_answer
+                ));
+            }
+        }
+
+        match self.view {
+            View::Puzzles => self.ui_puzzles(ui, &mut hunt),
+            View::Metas => ui_metas(ui, &hunt),
+            View::Transfer => self.ui_transfer(ui, &mut hunt),
+        }
+
+        ui.ctx().data_mut(|data| data.insert_persisted(id, hunt));
+    }
+}
@@ -0,0 +1,259 @@
+//! Hangul syllables taken apart into jamo and put back together, the way a Korean IME does.
+//! Jamo are always the compatibility jamo (U+3131 to U+3163), which are the ones people type.
+
+const SYLLABLE_START: u32 = 0xAC00;
+const SYLLABLE_END: u32 = 0xD7A3;
+const VOWEL_COUNT: u32 = 21;
+const FINAL_COUNT: u32 = 28;
+
+pub const INITIALS: [char; 19] = [
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ',
+    'ㅌ', 'ㅍ', 'ㅎ',
+];
+pub const VOWELS: [char; 21] = [
+    'ㅏ', 'ㅐ', 'ㅑ', 'ㅒ', 'ㅓ', 'ㅔ', 'ㅕ', 'ㅖ', 'ㅗ', 'ㅘ', 'ㅙ', 'ㅚ', 'ㅛ', 'ㅜ', 'ㅝ', 'ㅞ',
+    'ㅟ', 'ㅠ', 'ㅡ', 'ㅢ', 'ㅣ',
+];
+/// Final consonants, after the empty one at index 0.
+pub const FINALS: [char; 27] = [
+    'ㄱ', 'ㄲ', 'ㄳ', 'ㄴ', 'ㄵ', 'ㄶ', 'ㄷ', 'ㄹ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ', 'ㄿ', 'ㅀ', 'ㅁ',
+    'ㅂ', 'ㅄ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+/// Vowels and final consonants that are typed as two jamo.
+const COMPOUNDS: [(char, char, char); 18] = [
+    ('ㅗ', 'ㅏ', 'ㅘ'),
+    ('ㅗ', 'ㅐ', 'ㅙ'),
+    ('ㅗ', 'ㅣ', 'ㅚ'),
+    ('ㅜ', 'ㅓ', 'ㅝ'),
+    ('ㅜ', 'ㅔ', 'ㅞ'),
+    ('ㅜ', 'ㅣ', 'ㅟ'),
+    ('ㅡ', 'ㅣ', 'ㅢ'),
+    ('ㄱ', 'ㅅ', 'ㄳ'),
+    ('ㄴ', 'ㅈ', 'ㄵ'),
+    ('ㄴ', 'ㅎ', 'ㄶ'),
+    ('ㄹ', 'ㄱ', 'ㄺ'),
+    ('ㄹ', 'ㅁ', 'ㄻ'),
+    ('ㄹ', 'ㅂ', 'ㄼ'),
+    ('ㄹ', 'ㅅ', 'ㄽ'),
+    ('ㄹ', 'ㅌ', 'ㄾ'),
+    ('ㄹ', 'ㅍ', 'ㄿ'),
+    ('ㄹ', 'ㅎ', 'ㅀ'),
+    ('ㅂ', 'ㅅ', 'ㅄ'),
+];
+
+/// The Dubeolsik (standard two-set) keyboard. Other letters type the same jamo with Shift.
+#[rustfmt::skip]
+const KEYS: [(char, char); 33] = [
+    ('q', 'ㅂ'), ('w', 'ㅈ'), ('e', 'ㄷ'), ('r', 'ㄱ'), ('t', 'ㅅ'), ('y', 'ㅛ'), ('u', 'ㅕ'),
+    ('i', 'ㅑ'), ('o', 'ㅐ'), ('p', 'ㅔ'), ('a', 'ㅁ'), ('s', 'ㄴ'), ('d', 'ㅇ'), ('f', 'ㄹ'),
+    ('g', 'ㅎ'), ('h', 'ㅗ'), ('j', 'ㅓ'), ('k', 'ㅏ'), ('l', 'ㅣ'), ('z', 'ㅋ'), ('x', 'ㅌ'),
+    ('c', 'ㅊ'), ('v', 'ㅍ'), ('b', 'ㅠ'), ('n', 'ㅜ'), ('m', 'ㅡ'),
+    ('Q', 'ㅃ'), ('W', 'ㅉ'), ('E', 'ㄸ'), ('R', 'ㄲ'), ('T', 'ㅆ'), ('O', 'ㅒ'), ('P', 'ㅖ'),
+];
+
+/// Strokes in each jamo, as counted in Korean schools. Compounds are the sum of their parts.
+#[rustfmt::skip]
+const STROKES: [(char, usize); 33] = [
+    ('ㄱ', 1), ('ㄲ', 2), ('ㄴ', 1), ('ㄷ', 2), ('ㄸ', 4), ('ㄹ', 3), ('ㅁ', 3), ('ㅂ', 4),
+    ('ㅃ', 8), ('ㅅ', 2), ('ㅆ', 4), ('ㅇ', 1), ('ㅈ', 2), ('ㅉ', 4), ('ㅊ', 3), ('ㅋ', 2),
+    ('ㅌ', 3), ('ㅍ', 4), ('ㅎ', 3),
+    ('ㅏ', 2), ('ㅐ', 3), ('ㅑ', 3), ('ㅒ', 4), ('ㅓ', 2), ('ㅔ', 3), ('ㅕ', 3), ('ㅖ', 4),
+    ('ㅗ', 2), ('ㅛ', 3), ('ㅜ', 2), ('ㅠ', 3), ('ㅡ', 1), ('ㅣ', 1),
+];
+
+pub fn is_vowel(jamo: char) -> bool {
+    VOWELS.contains(&jamo)
+}
+
+fn is_consonant(jamo: char) -> bool {
+    INITIALS.contains(&jamo) || FINALS.contains(&jamo)
+}
+
+/// The initial, vowel and final jamo of a Hangul syllable.
+pub fn decompose(syllable: char) -> Option<(char, char, Option<char>)> {
+    let code = u32::from(syllable);
+    if !(SYLLABLE_START..=SYLLABLE_END).contains(&code) {
+        return None;
+    }
+    let index = code - SYLLABLE_START;
+    let initial = INITIALS[(index / (VOWEL_COUNT * FINAL_COUNT)) as usize];
+    let vowel = VOWELS[(index / FINAL_COUNT % VOWEL_COUNT) as usize];
+    let last = (index % FINAL_COUNT) as usize;
+    let last = last.checked_sub(1).map(|i| FINALS[i]);
+    Some((initial, vowel, last))
+}
+
+fn compose(initial: char, vowel: char, last: Option<char>) -> Option<char> {
+    let initial = INITIALS.iter().position(|&c| c == initial)? as u32;
+    let vowel = VOWELS.iter().position(|&c| c == vowel)? as u32;
+    let last = match last {
+        Some(last) => FINALS.iter().position(|&c| c == last)? as u32 + 1,
+        None => 0,
+    };
+    let code = SYLLABLE_START + (initial * VOWEL_COUNT + vowel) * FINAL_COUNT + last;
+    char::from_u32(code)
+}
+
+fn combine(first: char, second: char) -> Option<char> {
+    COMPOUNDS
+        .iter()
+        .find(|&&(a, b, _)| a == first && b == second)
+        .map(|&(_, _, compound)| compound)
+}
+
+/// The two jamo a compound vowel or final consonant is typed as.
+pub fn split(jamo: char) -> Option<(char, char)> {
+    COMPOUNDS
+        .iter()
+        .find(|&&(_, _, compound)| compound == jamo)
+        .map(|&(a, b, _)| (a, b))
+}
+
+/// The jamo of every syllable in the text, with compounds split into the jamo they are typed as
+/// if `split_compounds` is set. Anything that is not a syllable is kept.
+pub fn to_jamo(text: &str, split_compounds: bool) -> String {
+    let mut jamo = String::new();
+    let mut push = |c: char| match split(c).filter(|_| split_compounds) {
+        Some((a, b)) => {
+            jamo.push(a);
+            jamo.push(b);
+        }
+        None => jamo.push(c),
+    };
+    for c in text.chars() {
+        match decompose(c) {
+            Some((initial, vowel, last)) => {
+                push(initial);
+                push(vowel);
+                last.into_iter().for_each(&mut push);
+            }
+            None => push(c),
+        }
+    }
+    jamo
+}
+
+/// A syllable being typed.
+#[derive(Default)]
+struct Block {
+    initial: Option<char>,
+    vowel: Option<char>,
+    last: Option<char>,
+}
+
+impl Block {
+    fn flush(&mut self, out: &mut String) {
+        let block = std::mem::take(self);
+        let syllable = match (block.initial, block.vowel) {
+            (Some(initial), Some(vowel)) => compose(initial, vowel, block.last),
+            _ => None,
+        };
+        match syllable {
+            Some(syllable) => out.push(syllable),
+            None => out.extend(
+                [block.initial, block.vowel, block.last]
+                    .into_iter()
+                    .flatten(),
+            ),
+        }
+    }
+}
+
+/// Puts jamo together into syllables the way a Korean IME does as they are typed,
+/// so a final consonant moves on to the next syllable when a vowel follows it.
+pub fn from_jamo(text: &str) -> String {
+    let mut out = String::new();
+    let mut block = Block::default();
+    for jamo in to_jamo(text, true).chars() {
+        type_jamo(&mut block, &mut out, jamo);
+    }
+    block.flush(&mut out);
+    out
+}
+
+fn type_jamo(block: &mut Block, out: &mut String, jamo: char) {
+    if is_vowel(jamo) {
+        match (block.initial, block.vowel, block.last) {
+            (Some(_), None, None) => block.vowel = Some(jamo),
+            (_, Some(vowel), None) if combine(vowel, jamo).is_some() => {
+                block.vowel = combine(vowel, jamo);
+            }
+            (Some(_), Some(_), Some(last)) => {
+                // The final consonant, or the second half of a compound one, starts the next syllable.
+                let (kept, moved) = split(last).map_or((None, last), |(a, b)| (Some(a), b));
+                block.last = kept;
+                block.flush(out);
+                block.initial = Some(moved);
+                block.vowel = Some(jamo);
+            }
+            _ => {
+                block.flush(out);
+                block.vowel = Some(jamo);
+            }
+        }
+    } else if is_consonant(jamo) {
+        match (block.initial, block.vowel, block.last) {
+            (Some(_), Some(_), None) if FINALS.contains(&jamo) => block.last = Some(jamo),
+            (Some(_), Some(_), Some(last)) if combine(last, jamo).is_some() => {
+                block.last = combine(last, jamo);
+            }
+            _ => {
+                block.flush(out);
+                block.initial = Some(jamo);
+            }
+        }
+    } else {
+        block.flush(out);
+        out.push(jamo);
+    }
+}
+
+/// The keys pressed on a Dubeolsik keyboard to type the text.
+pub fn to_keys(text: &str) -> String {
+    to_jamo(text, true)
+        .chars()
+        .map(|jamo| {
+            KEYS.iter()
+                .find(|&&(_, other)| other == jamo)
+                .map_or(jamo, |&(key, _)| key)
+        })
+        .collect()
+}
+
+/// The Hangul typed by pressing these keys on a Dubeolsik keyboard.
+pub fn from_keys(keys: &str) -> String {
+    let jamo = keys
+        .chars()
+        .map(|key| {
+            let jamo = |key| KEYS.iter().find(|&&(other, _)| other == key);
+            jamo(key)
+                .or_else(|| jamo(key.to_ascii_lowercase()))
+                .map_or(key, |&(_, jamo)| jamo)
+        })
+        .collect::<String>();
+    from_jamo(&jamo)
+}
+
+/// Strokes to write the jamo, or nothing for anything else.
+pub fn strokes(jamo: char) -> Option<usize> {
+    if let Some((a, b)) = split(jamo) {
+        return Some(strokes(a)? + strokes(b)?);
+    }
+    STROKES
+        .iter()
+        .find(|&&(other, _)| other == jamo)
+        .map(|&(_, count)| count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_jamo, from_keys};
+
+    #[test]
+    fn composes_keys_and_jamo() {
+        assert_eq!(from_keys("dkssud"), "안녕");
+        assert_eq!(from_jamo("ㅇㅓㅂㅅㅇㅓ"), "없어");
+        assert_eq!(from_jamo("ㅎㅗㅏ"), "화");
+    }
+}
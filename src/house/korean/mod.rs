@@ -0,0 +1,130 @@
+mod hangul;
+mod romanize;
+
+use egui::RichText;
+
+use crate::house::HouseGadget;
+use crate::house::scratchpad::result_label;
+
+const HANGUL_FONT_SIZE: f32 = 16.0;
+
+/// The jamo of a syllable or a lone jamo, or nothing for anything else.
+fn jamo_of(c: char, split_compounds: bool) -> Option<String> {
+    let jamo = hangul::to_jamo(&c.to_string(), split_compounds);
+    let all_jamo = jamo.chars().all(|jamo| hangul::strokes(jamo).is_some());
+    all_jamo.then_some(jamo)
+}
+
+fn numbers(numbers: &[usize]) -> String {
+    let numbers = numbers.iter().map(ToString::to_string).collect::<Vec<_>>();
+    numbers.join(" ")
+}
+
+pub struct KoreanTools {
+    input: String,
+    /// Whether ㅘ is shown as ㅗㅏ and ㄳ as ㄱㅅ, the way they are typed.
+    split_compounds: bool,
+}
+
+impl HouseGadget for KoreanTools {
+    fn new() -> Self {
+        Self {
+            input: String::new(),
+            split_compounds: false,
+        }
+    }
+
+    fn title(&self) -> String {
+        "Korean tools".to_owned()
+    }
+
+    fn input(&mut self) -> Option<&mut String> {
+        Some(&mut self.input)
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.label("Hangul, jamo, or keys typed on a Dubeolsik keyboard");
+        ui.text_edit_multiline(&mut self.input);
+        ui.checkbox(&mut self.split_compounds, "Split compound jamo")
+            .on_hover_text("Show ㅘ as ㅗㅏ and ㄳ as ㄱㅅ, the way they are typed");
+        if self.input.trim().is_empty() {
+            return;
+        }
+
+        let mut results = vec![
+            ("Jamo", hangul::to_jamo(&self.input, self.split_compounds)),
+            ("Composed", hangul::from_jamo(&self.input)),
+            ("Dubeolsik keys", hangul::to_keys(&self.input)),
+        ];
+        if self.input.chars().any(|c| c.is_ascii_alphabetic()) {
+            results.push(("Typed from keys", hangul::from_keys(&self.input)));
+        }
+        results.push(("Romanization", romanize::romanize(&self.input)));
+        ui.separator();
+        egui::Grid::new("Korean").striped(true).show(ui, |ui| {
+            for (name, result) in &results {
+                ui.label(*name);
+                result_label(ui, RichText::new(result).size(HANGUL_FONT_SIZE), result);
+                ui.end_row();
+            }
+        });
+
+        ui.separator();
+        ui.heading("Counts");
+        self.ui_counts(ui);
+    }
+}
+
+impl KoreanTools {
+    fn ui_counts(&self, ui: &mut egui::Ui) {
+        let counted = self
+            .input
+            .chars()
+            .filter_map(|c| Some((c, jamo_of(c, self.split_compounds)?)))
+            .collect::<Vec<_>>();
+        if counted.is_empty() {
+            ui.label("No Hangul to count");
+            return;
+        }
+        let mut jamo_counts = Vec::new();
+        let mut stroke_counts = Vec::new();
+        egui::Grid::new("Korean counts")
+            .striped(true)
+            .show(ui, |ui| {
+                for heading in ["", "Jamo", "Jamo count", "Strokes"] {
+                    ui.strong(heading);
+                }
+                ui.end_row();
+                for (c, jamo) in &counted {
+                    let count = jamo.chars().count();
+                    let strokes = jamo.chars().filter_map(hangul::strokes).sum::<usize>();
+                    ui.label(RichText::new(c.to_string()).size(HANGUL_FONT_SIZE));
+                    ui.label(jamo);
+                    ui.label(count.to_string());
+                    ui.label(strokes.to_string());
+                    ui.end_row();
+                    jamo_counts.push(count);
+                    stroke_counts.push(strokes);
+                }
+                ui.strong("Total");
+                ui.label("");
+                ui.strong(jamo_counts.iter().sum::<usize>().to_string());
+                ui.strong(stroke_counts.iter().sum::<usize>().to_string());
+                ui.end_row();
+            });
+        ui.horizontal(|ui| {
+            ui.label("Jamo counts:");
+            let jamo_counts = numbers(&jamo_counts);
+            result_label(ui, RichText::new(&jamo_counts).monospace(), &jamo_counts);
+        });
+        ui.horizontal(|ui| {
+            ui.label("Stroke counts:");
+            let stroke_counts = numbers(&stroke_counts);
+            result_label(
+                ui,
+                RichText::new(&stroke_counts).monospace(),
+                &stroke_counts,
+            );
+        });
+    }
+}
@@ -0,0 +1,155 @@
+//! Revised Romanization of Korean, with the sound changes between syllables that it spells out:
+//! linking, the silent ㅎ before a vowel, nasalization, ㄹ assimilation, aspiration and
+//! palatalization.
+//! Tensing is not written in the Revised Romanization, so it is left alone. Aspiration is
+//! written even in nouns, where the rules keep the h (묵호 comes out muko, not Mukho).
+
+use super::hangul::{INITIALS, VOWELS, decompose, split};
+
+#[rustfmt::skip]
+const INITIAL_LETTERS: [&str; 19] = [
+    "g", "kk", "n", "d", "tt", "r", "m", "b", "pp", "s", "ss", "", "j", "jj", "ch", "k", "t", "p", "h",
+];
+#[rustfmt::skip]
+const VOWEL_LETTERS: [&str; 21] = [
+    "a", "ae", "ya", "yae", "eo", "e", "yeo", "ye", "o", "wa", "wae", "oe", "yo", "u", "wo", "we",
+    "wi", "yu", "eu", "ui", "i",
+];
+
+type Syllable = (char, char, Option<char>);
+
+/// The sound a final consonant is pronounced as at the end of a word.
+fn representative(last: char) -> char {
+    match last {
+        'ㄱ' | 'ㄲ' | 'ㅋ' | 'ㄳ' | 'ㄺ' => 'ㄱ',
+        'ㄴ' | 'ㄵ' | 'ㄶ' => 'ㄴ',
+        'ㄹ' | 'ㄼ' | 'ㄽ' | 'ㄾ' | 'ㅀ' => 'ㄹ',
+        'ㅁ' | 'ㄻ' => 'ㅁ',
+        'ㅂ' | 'ㅍ' | 'ㅄ' | 'ㄿ' => 'ㅂ',
+        'ㅇ' => 'ㅇ',
+        _ => 'ㄷ',
+    }
+}
+
+fn aspirated(consonant: char) -> Option<char> {
+    match representative(consonant) {
+        'ㄱ' => Some('ㅋ'),
+        'ㄷ' if matches!(consonant, 'ㅈ' | 'ㅊ') => Some('ㅊ'),
+        'ㄷ' => Some('ㅌ'),
+        'ㅂ' => Some('ㅍ'),
+        _ => None,
+    }
+}
+
+/// How a final consonant and the next syllable's initial are pronounced together.
+fn sound_change(last: char, initial: char, vowel: char) -> (Option<char>, char) {
+    let (first, second) = split(last).map_or((None, last), |(a, b)| (Some(a), b));
+    if initial == 'ㅇ' {
+        return match second {
+            'ㅇ' => (Some(last), initial),
+            'ㅎ' => (None, first.unwrap_or(initial)),
+            'ㄷ' if vowel == 'ㅣ' => (first, 'ㅈ'),
+            'ㅌ' if vowel == 'ㅣ' => (first, 'ㅊ'),
+            _ => (first, second),
+        };
+    }
+    if second == 'ㅎ' {
+        return match initial {
+            'ㄴ' => (first.or(Some('ㄴ')), 'ㄴ'),
+            'ㅅ' => (first, 'ㅅ'),
+            _ => (first, aspirated(initial).unwrap_or(initial)),
+        };
+    }
+    if initial == 'ㅎ' {
+        if let Some(aspirated) = aspirated(second) {
+            return (first, aspirated);
+        }
+    }
+    let last = representative(last);
+    match (last, initial) {
+        ('ㄱ', 'ㄴ' | 'ㅁ') => (Some('ㅇ'), initial),
+        ('ㄷ', 'ㄴ' | 'ㅁ') => (Some('ㄴ'), initial),
+        ('ㅂ', 'ㄴ' | 'ㅁ') => (Some('ㅁ'), initial),
+        ('ㄱ', 'ㄹ') => (Some('ㅇ'), 'ㄴ'),
+        ('ㄷ', 'ㄹ') => (Some('ㄴ'), 'ㄴ'),
+        ('ㅂ', 'ㄹ') => (Some('ㅁ'), 'ㄴ'),
+        ('ㅁ' | 'ㅇ', 'ㄹ') => (Some(last), 'ㄴ'),
+        ('ㄴ' | 'ㄹ', 'ㄹ') | ('ㄹ', 'ㄴ') => (Some('ㄹ'), 'ㄹ'),
+        _ => (Some(last), initial),
+    }
+}
+
+fn final_letters(last: char) -> &'static str {
+    match representative(last) {
+        'ㄱ' => "k",
+        'ㄴ' => "n",
+        'ㄹ' => "l",
+        'ㅁ' => "m",
+        'ㅂ' => "p",
+        'ㅇ' => "ng",
+        _ => "t",
+    }
+}
+
+fn romanize_word(mut syllables: Vec<Syllable>, out: &mut String) {
+    for i in 1..syllables.len() {
+        let (initial, vowel, _) = syllables[i];
+        if let Some(last) = syllables[i - 1].2 {
+            let (last, initial) = sound_change(last, initial, vowel);
+            syllables[i - 1].2 = last;
+            syllables[i].0 = initial;
+        }
+    }
+    let mut after_l = false;
+    for (initial, vowel, last) in syllables {
+        if initial == 'ㄹ' && after_l {
+            out.push('l');
+        } else if let Some(i) = INITIALS.iter().position(|&c| c == initial) {
+            out.push_str(INITIAL_LETTERS[i]);
+        }
+        if let Some(i) = VOWELS.iter().position(|&c| c == vowel) {
+            out.push_str(VOWEL_LETTERS[i]);
+        }
+        after_l = last == Some('ㄹ');
+        out.extend(last.map(final_letters));
+    }
+}
+
+/// The Revised Romanization of the text. Anything that is not Hangul is kept as it is.
+pub fn romanize(text: &str) -> String {
+    let mut out = String::new();
+    let mut word = Vec::new();
+    for c in text.chars() {
+        if let Some(syllable) = decompose(c) {
+            word.push(syllable);
+        } else {
+            romanize_word(std::mem::take(&mut word), &mut out);
+            out.push(c);
+        }
+    }
+    romanize_word(word, &mut out);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::romanize;
+
+    #[test]
+    fn romanizes_with_sound_changes() {
+        for (hangul, roman) in [
+            ("안녕하세요", "annyeonghaseyo"),
+            ("한국어", "hangugeo"),
+            ("신라", "silla"),
+            ("종로", "jongno"),
+            ("같이", "gachi"),
+            ("좋고", "joko"),
+            ("없어", "eopseo"),
+            ("싫어", "sireo"),
+            ("좋소", "joso"),
+            ("닭", "dak"),
+        ] {
+            assert_eq!(romanize(hangul), roman, "{hangul}");
+        }
+    }
+}
@@ -1,8 +1,28 @@
+pub mod bus;
+mod character_map;
+pub mod color_emoji;
+mod converter;
 mod emoji;
+mod hunt_tracker;
+mod korean;
 mod puzzle_hunt_tools;
+mod regex_tester;
+pub mod scratchpad;
+mod unicode_inspector;
+mod words;
 
+use bus::Message;
+use character_map::CharacterMap;
+use converter::NumberConverter;
 use emoji::EmojiPicker;
-use puzzle_hunt_tools::PuzzleHuntTools;
+use hunt_tracker::HuntTracker;
+use korean::KoreanTools;
+use puzzle_hunt_tools::{CipherIdentifier, PuzzleHuntTools, TextWorkbench};
+use regex_tester::RegexTester;
+use scratchpad::Scratchpad;
+use strum::IntoEnumIterator as _;
+use strum_macros::EnumIter;
+use unicode_inspector::UnicodeInspector;
 
 use crate::MyApp;
 
@@ -15,6 +35,22 @@ pub trait HouseGadget {
 
     fn ui(&mut self, ui: &mut egui::Ui);
 
+    /// The text box that other gadgets fill in, if the gadget has one.
+    fn input(&mut self) -> Option<&mut String> {
+        None
+    }
+
+    /// Called with every message on the bus; `id` is this gadget's window.
+    fn receive(&mut self, id: egui::Id, message: &Message) {
+        if let Message::SetInput { to, text } = message {
+            if *to == id {
+                if let Some(input) = self.input() {
+                    text.clone_into(input);
+                }
+            }
+        }
+    }
+
     fn show(&mut self, id: &egui::Id, ctx: &egui::Context, open: &mut bool) {
         egui::Window::new(self.title())
             .id(*id)
@@ -25,15 +61,78 @@ pub trait HouseGadget {
     }
 }
 
-pub fn register_gadgets(app: &mut MyApp, ui: &mut egui::Ui) {
-    if ui.button("Emoji picker").clicked() {
-        let widget = EmojiPicker::new();
-        let id = app.new_id();
-        app.instances.push((id, true, Box::new(widget)));
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter)]
+pub enum GadgetKind {
+    EmojiPicker,
+    PuzzleHuntTools,
+    HuntTracker,
+    Scratchpad,
+    CipherIdentifier,
+    NumberConverter,
+    UnicodeInspector,
+    CharacterMap,
+    KoreanTools,
+    TextWorkbench,
+    RegexTester,
+}
+
+impl GadgetKind {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::EmojiPicker => "Emoji picker",
+            Self::PuzzleHuntTools => "Puzzle hunt tools",
+            Self::HuntTracker => "Hunt tracker",
+            Self::Scratchpad => "Scratchpad",
+            Self::CipherIdentifier => "What is this?",
+            Self::NumberConverter => "Number converter",
+            Self::UnicodeInspector => "Unicode inspector",
+            Self::CharacterMap => "Character map",
+            Self::KoreanTools => "Korean tools",
+            Self::TextWorkbench => "Text workbench",
+            Self::RegexTester => "Regex tester",
+        }
     }
-    if ui.button("Puzzle hunt tools").clicked() {
-        let widget = PuzzleHuntTools::new();
-        let id = app.new_id();
-        app.instances.push((id, true, Box::new(widget)));
+
+    pub fn create(self) -> Box<dyn HouseGadget> {
+        match self {
+            Self::EmojiPicker => Box::new(EmojiPicker::new()),
+            Self::PuzzleHuntTools => Box::new(PuzzleHuntTools::new()),
+            Self::HuntTracker => Box::new(HuntTracker::new()),
+            Self::Scratchpad => Box::new(Scratchpad::new()),
+            Self::CipherIdentifier => Box::new(CipherIdentifier::new()),
+            Self::NumberConverter => Box::new(NumberConverter::new()),
+            Self::UnicodeInspector => Box::new(UnicodeInspector::new()),
+            Self::CharacterMap => Box::new(CharacterMap::new()),
+            Self::KoreanTools => Box::new(KoreanTools::new()),
+            Self::TextWorkbench => Box::new(TextWorkbench::new()),
+            Self::RegexTester => Box::new(RegexTester::new()),
+        }
+    }
+
+    /// Whether text can be opened in this gadget, for the "Open in" menus.
+    ///
+    /// This is narrower than `HouseGadget::input`: the character map and emoji picker only
+    /// search by name, and the hunt tracker's input is an answer for whichever puzzle is
+    /// selected, so opening a result in a new one of those is no use. Open ones still get
+    /// text sent to their `input` from the scratchpad.
+    pub fn takes_text(self) -> bool {
+        matches!(
+            self,
+            Self::PuzzleHuntTools
+                | Self::CipherIdentifier
+                | Self::NumberConverter
+                | Self::UnicodeInspector
+                | Self::KoreanTools
+                | Self::TextWorkbench
+                | Self::RegexTester
+        )
+    }
+}
+
+pub fn register_gadgets(app: &mut MyApp, ui: &mut egui::Ui) {
+    for kind in GadgetKind::iter() {
+        if ui.button(kind.name()).clicked() {
+            app.open(kind);
+        }
     }
 }
@@ -0,0 +1,133 @@
+//! Plays short mono tones, natively through `rodio` and on the web through Web Audio.
+
+pub const SAMPLE_RATE: u32 = 44_100;
+
+const VOLUME: f32 = 0.3;
+const RAMP_SECONDS: f32 = 0.004;
+
+/// Renders on/off segments (in seconds) into a sine tone.
+/// Each tone fades in and out over a few milliseconds so that keying doesn't click.
+pub fn tone_samples(segments: &[(bool, f32)], frequency: f32) -> Vec<f32> {
+    let rate = SAMPLE_RATE as f32;
+    let ramp = (RAMP_SECONDS * rate).max(1.0);
+    let mut samples = Vec::new();
+    for &(on, seconds) in segments {
+        let count = (seconds * rate).round() as usize;
+        if !on {
+            samples.extend(std::iter::repeat_n(0.0, count));
+            continue;
+        }
+        samples.extend((0..count).map(|i| {
+            let envelope = (i as f32 / ramp).min((count - i) as f32 / ramp).min(1.0);
+            let phase = std::f32::consts::TAU * frequency * i as f32 / rate;
+            VOLUME * envelope * phase.sin()
+        }));
+    }
+    samples
+}
+
+#[cfg(target_arch = "wasm32")]
+mod backend {
+    use super::SAMPLE_RATE;
+
+    pub struct AudioOutput {
+        context: web_sys::AudioContext,
+        source: Option<web_sys::AudioBufferSourceNode>,
+    }
+
+    impl AudioOutput {
+        pub fn new() -> Option<Self> {
+            let context = web_sys::AudioContext::new().ok()?;
+            Some(Self {
+                context,
+                source: None,
+            })
+        }
+
+        pub fn play(&mut self, samples: &[f32]) {
+            self.stop();
+            if samples.is_empty() {
+                return;
+            }
+            let Ok(buffer) =
+                self.context
+                    .create_buffer(1, samples.len() as u32, SAMPLE_RATE as f32)
+            else {
+                return;
+            };
+            let Ok(source) = self.context.create_buffer_source() else {
+                return;
+            };
+            if buffer.copy_to_channel(samples, 0).is_err() {
+                return;
+            }
+            source.set_buffer(Some(&buffer));
+            let destination = self.context.destination();
+            if source.connect_with_audio_node(&destination).is_ok() && source.start().is_ok() {
+                self.source = Some(source);
+            }
+        }
+
+        pub fn stop(&mut self) {
+            if let Some(source) = self.source.take() {
+                let node: &web_sys::AudioScheduledSourceNode = &source;
+                node.stop().ok();
+            }
+        }
+    }
+}
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "native_audio"))]
+mod backend {
+    use super::SAMPLE_RATE;
+
+    pub struct AudioOutput {
+        _stream: rodio::OutputStream,
+        sink: rodio::Sink,
+    }
+
+    impl AudioOutput {
+        pub fn new() -> Option<Self> {
+            let mut stream = rodio::OutputStreamBuilder::open_default_stream().ok()?;
+            stream.log_on_drop(false);
+            let sink = rodio::Sink::connect_new(stream.mixer());
+            Some(Self {
+                _stream: stream,
+                sink,
+            })
+        }
+
+        pub fn play(&self, samples: &[f32]) {
+            self.sink.clear();
+            let buffer = rodio::buffer::SamplesBuffer::new(1, SAMPLE_RATE, samples);
+            self.sink.append(buffer);
+            self.sink.play();
+        }
+
+        pub fn stop(&self) {
+            self.sink.clear();
+        }
+    }
+}
+
+#[cfg(all(not(target_arch = "wasm32"), not(feature = "native_audio")))]
+mod backend {
+    /// Native builds without the `native_audio` feature have no audio output.
+    pub struct AudioOutput(std::convert::Infallible);
+
+    impl AudioOutput {
+        pub fn new() -> Option<Self> {
+            None
+        }
+
+        pub fn play(&self, _samples: &[f32]) {
+            match self.0 {}
+        }
+
+        pub fn stop(&self) {
+            match self.0 {}
+        }
+    }
+}
+
+pub use backend::AudioOutput;
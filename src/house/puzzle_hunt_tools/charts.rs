@@ -0,0 +1,684 @@
+//! Tables and drawn reference charts for the picture-based codes.
+
+use egui::{Color32, Painter, Rect, RichText, Sense, Shape, Stroke, StrokeKind, Vec2, pos2, vec2};
+
+use super::ALPHABET_CNT;
+
+pub const GLYPH_SIZE: Vec2 = vec2(36.0, 36.0);
+pub const FLAG_SIZE: Vec2 = vec2(42.0, 28.0);
+pub const DANCER_SIZE: Vec2 = vec2(30.0, 42.0);
+pub const HAND_SIZE: Vec2 = vec2(42.0, 42.0);
+const CHART_COLUMNS: usize = 9;
+
+// Pigpen criteria are top, left, dot, right, bottom and X-shaped.
+// For the X-shaped letters, the marked side is where the point of the V faces.
+pub const PIGPEN: [&str; ALPHABET_CNT] = [
+    "000110", "010110", "010010", "100110", "110110", // ABCDE
+    "110010", "100100", "110100", "110000", "001110", // FGHIJ
+    "011110", "011010", "101110", "111110", "111010", // KLMNO
+    "101100", "111100", "111000", "000011", "000101", // PQRST
+    "010001", "100001", "001011", "001101", "011001", // UVWXY
+    "101001", // Z
+];
+pub const PIGPEN_LABELS: [char; 6] = ['━', '┃', '⏺', '┃', '━', '╳'];
+
+const RED: Color32 = Color32::from_rgb(210, 30, 40);
+const WHITE: Color32 = Color32::WHITE;
+const BLUE: Color32 = Color32::from_rgb(20, 60, 160);
+const YELLOW: Color32 = Color32::from_rgb(250, 205, 0);
+const BLACK: Color32 = Color32::BLACK;
+
+// Flag criteria are the colors that appear on the flag.
+pub const FLAG_COLORS: [Color32; 5] = [RED, WHITE, BLUE, YELLOW, BLACK];
+pub const FLAGS: [&str; ALPHABET_CNT] = [
+    "01100", "10000", "11100", "00110", "10100", // ABCDE
+    "11000", "00110", "11000", "00011", "01100", // FGHIJ
+    "00110", "00011", "01100", "01100", "10010", // KLMNO
+    "01100", "00010", "10010", "01100", "11100", // PQRST
+    "11000", "11000", "11100", "01100", "10010", // UVWXY
+    "10111", // Z
+];
+
+/// International maritime signal flags, drawn with the hoist on the left.
+enum Flag {
+    Stripes(&'static [Color32]),
+    Bands(&'static [Color32]),
+    Swallowtail(&'static [Color32]),
+    Square(Color32, Color32),
+    Cross(Color32, Color32),
+    Saltire(Color32, Color32),
+    Disc(Color32, Color32),
+    Diamond(Color32, Color32),
+    Checkers(Color32, Color32, usize),
+    Diagonal(Color32, Color32),
+    DiagonalStripes(Color32, Color32),
+    Frames(&'static [Color32]),
+    /// Top, fly, bottom and hoist.
+    Triangles([Color32; 4]),
+}
+
+const FLAG_DESIGNS: [Flag; ALPHABET_CNT] = [
+    Flag::Swallowtail(&[WHITE, BLUE]),
+    Flag::Swallowtail(&[RED]),
+    Flag::Bands(&[BLUE, WHITE, RED, WHITE, BLUE]),
+    Flag::Bands(&[YELLOW, BLUE, BLUE, YELLOW]),
+    Flag::Bands(&[BLUE, RED]),
+    Flag::Diamond(WHITE, RED),
+    Flag::Stripes(&[YELLOW, BLUE, YELLOW, BLUE, YELLOW, BLUE]),
+    Flag::Stripes(&[WHITE, RED]),
+    Flag::Disc(YELLOW, BLACK),
+    Flag::Bands(&[BLUE, WHITE, BLUE]),
+    Flag::Stripes(&[YELLOW, BLUE]),
+    Flag::Checkers(YELLOW, BLACK, 2),
+    Flag::Saltire(BLUE, WHITE),
+    Flag::Checkers(BLUE, WHITE, 4),
+    Flag::Diagonal(YELLOW, RED),
+    Flag::Square(BLUE, WHITE),
+    Flag::Stripes(&[YELLOW]),
+    Flag::Cross(RED, YELLOW),
+    Flag::Square(WHITE, BLUE),
+    Flag::Stripes(&[RED, WHITE, BLUE]),
+    Flag::Checkers(RED, WHITE, 2),
+    Flag::Saltire(WHITE, RED),
+    Flag::Frames(&[BLUE, WHITE, RED]),
+    Flag::Cross(WHITE, BLUE),
+    Flag::DiagonalStripes(YELLOW, RED),
+    Flag::Triangles([YELLOW, BLUE, RED, BLACK]),
+];
+
+pub const NATO: [&str; 36] = [
+    "Alfa", "Bravo", "Charlie", "Delta", "Echo", "Foxtrot", "Golf", "Hotel", "India", "Juliett",
+    "Kilo", "Lima", "Mike", "November", "Oscar", "Papa", "Quebec", "Romeo", "Sierra", "Tango",
+    "Uniform", "Victor", "Whiskey", "X-ray", "Yankee", "Zulu", "Zero", "One", "Two", "Three",
+    "Four", "Five", "Six", "Seven", "Eight", "Nine",
+];
+/// Other spellings people use, and the letter or digit they stand for.
+const NATO_VARIANTS: [(&str, char); 7] = [
+    ("alpha", 'A'),
+    ("juliet", 'J'),
+    ("xray", 'X'),
+    ("fower", '4'),
+    ("fife", '5'),
+    ("niner", '9'),
+    ("tree", '3'),
+];
+
+pub const RESISTOR_COLORS: [(&str, Color32); 10] = [
+    ("Black", Color32::BLACK),
+    ("Brown", Color32::from_rgb(120, 70, 30)),
+    ("Red", RED),
+    ("Orange", Color32::from_rgb(240, 130, 20)),
+    ("Yellow", YELLOW),
+    ("Green", Color32::from_rgb(30, 150, 50)),
+    ("Blue", BLUE),
+    ("Violet", Color32::from_rgb(130, 50, 170)),
+    ("Grey", Color32::GRAY),
+    ("White", Color32::WHITE),
+];
+const RESISTOR_VARIANTS: [(&str, u8); 2] = [("purple", 7), ("gray", 8)];
+
+pub const ASL: [&str; ALPHABET_CNT] = [
+    "Fist, thumb against the side",
+    "Flat hand up, thumb across the palm",
+    "Hand curved into a C",
+    "Index up, other fingers touch the thumb",
+    "Fingertips bent down onto the thumb",
+    "Thumb and index touch, other fingers up",
+    "Index and thumb point sideways",
+    "Index and middle point sideways",
+    "Pinky up, fist",
+    "Pinky up, traces a J",
+    "Index and middle up, thumb between them",
+    "Thumb and index form an L",
+    "Thumb under three fingers",
+    "Thumb under two fingers",
+    "Fingertips touch the thumb in an O",
+    "Like K, pointing down",
+    "Like G, pointing down",
+    "Index and middle crossed",
+    "Fist, thumb across the fingers",
+    "Thumb between index and middle",
+    "Index and middle up together",
+    "Index and middle up apart",
+    "Index, middle and ring up apart",
+    "Index bent into a hook",
+    "Thumb and pinky out",
+    "Index traces a Z",
+];
+
+/// Dancing Men poses as left arm, right arm, left leg and right leg, as seen by the reader.
+/// Arms are up, out, down, on the head or on the hip. Legs are straight, wide, bent or kicking.
+#[rustfmt::skip]
+const DANCERS: [&str; ALPHABET_CNT] = [
+    "UDSS", "OOSS", "DUSS", "UUSS", "DDSS", // ABCDE
+    "ODWW", "DOWW", "UOSB", "OUBS", "HHSS", // FGHIJ
+    "PPSS", "HPWW", "PHWW", "UUBB", "DDKS", // KLMNO
+    "OOSK", "HUSS", "UHSS", "PDBB", "DPWW", // PQRST
+    "HOSK", "OHKS", "PUSB", "UPBS", "HDWS", // UVWXY
+    "DHSW", // Z
+];
+
+/// Where a finger points: extended, spread apart, crossed, hooked, curled into the palm,
+/// rounded into a C, or touching the thumb.
+#[derive(Clone, Copy)]
+enum Finger {
+    Up,
+    Spread,
+    Crossed,
+    Hooked,
+    Curled,
+    Rounded,
+    Touching,
+}
+
+#[derive(Clone, Copy)]
+enum Thumb {
+    /// Against the side of the hand.
+    Side,
+    /// Folded across the palm or the curled fingers.
+    Across,
+    /// Sticking out sideways.
+    Out,
+    /// Pointing the same way as the index finger.
+    Along,
+    /// Curved to meet the fingertips.
+    Meeting,
+    /// Up between the index and middle fingers.
+    Between,
+    /// Tucked under the curled fingers, peeking out after this many of them.
+    Under(usize),
+}
+
+/// A fingerspelled letter as a right hand seen from the front, with the palm facing out.
+struct Hand {
+    /// Index, middle, ring and pinky.
+    fingers: [Finger; 4],
+    thumb: Thumb,
+    /// Clockwise quarter turns, for the letters that point sideways or down.
+    turn: f32,
+    /// The path traced by the letters that move.
+    trace: &'static [(f32, f32)],
+}
+
+const fn hand(fingers: [Finger; 4], thumb: Thumb, turn: f32) -> Hand {
+    Hand {
+        fingers,
+        thumb,
+        turn,
+        trace: &[],
+    }
+}
+
+const FIST: [Finger; 4] = [Finger::Curled; 4];
+const POINT: [Finger; 4] = [Finger::Up, Finger::Curled, Finger::Curled, Finger::Curled];
+const TWO: [Finger; 4] = [Finger::Up, Finger::Up, Finger::Curled, Finger::Curled];
+const PINKY: [Finger; 4] = [Finger::Curled, Finger::Curled, Finger::Curled, Finger::Up];
+
+const HANDS: [Hand; ALPHABET_CNT] = [
+    hand(FIST, Thumb::Side, 0.0),
+    hand([Finger::Up; 4], Thumb::Across, 0.0),
+    hand([Finger::Rounded; 4], Thumb::Meeting, 0.0),
+    hand(
+        [
+            Finger::Up,
+            Finger::Touching,
+            Finger::Touching,
+            Finger::Touching,
+        ],
+        Thumb::Meeting,
+        0.0,
+    ),
+    hand([Finger::Hooked; 4], Thumb::Across, 0.0),
+    hand(
+        [Finger::Touching, Finger::Up, Finger::Up, Finger::Up],
+        Thumb::Meeting,
+        0.0,
+    ),
+    hand(POINT, Thumb::Along, 1.0),
+    hand(TWO, Thumb::Across, 1.0),
+    hand(PINKY, Thumb::Across, 0.0),
+    Hand {
+        trace: &[(0.3, -0.45), (0.3, 0.6), (0.55, 0.75), (0.75, 0.6)],
+        ..hand(PINKY, Thumb::Across, 0.0)
+    },
+    hand(TWO, Thumb::Between, 0.0),
+    hand(POINT, Thumb::Out, 0.0),
+    hand(FIST, Thumb::Under(3), 0.0),
+    hand(FIST, Thumb::Under(2), 0.0),
+    hand([Finger::Touching; 4], Thumb::Meeting, 0.0),
+    hand(TWO, Thumb::Between, 2.0),
+    hand(POINT, Thumb::Along, 2.0),
+    hand(
+        [
+            Finger::Crossed,
+            Finger::Crossed,
+            Finger::Curled,
+            Finger::Curled,
+        ],
+        Thumb::Across,
+        0.0,
+    ),
+    hand(FIST, Thumb::Across, 0.0),
+    hand(FIST, Thumb::Under(1), 0.0),
+    hand(TWO, Thumb::Across, 0.0),
+    hand(
+        [
+            Finger::Spread,
+            Finger::Spread,
+            Finger::Curled,
+            Finger::Curled,
+        ],
+        Thumb::Across,
+        0.0,
+    ),
+    hand(
+        [
+            Finger::Spread,
+            Finger::Spread,
+            Finger::Spread,
+            Finger::Curled,
+        ],
+        Thumb::Across,
+        0.0,
+    ),
+    hand(
+        [
+            Finger::Hooked,
+            Finger::Curled,
+            Finger::Curled,
+            Finger::Curled,
+        ],
+        Thumb::Across,
+        0.0,
+    ),
+    hand(PINKY, Thumb::Out, 0.0),
+    Hand {
+        trace: &[(0.0, -0.6), (-0.6, -0.6), (0.0, -0.2), (-0.6, -0.2)],
+        ..hand(POINT, Thumb::Across, 0.0)
+    },
+];
+
+/// Looks up a NATO code word, including common alternative spellings.
+pub fn nato_letter(word: &str) -> Option<char> {
+    let word = word.to_lowercase();
+    NATO.iter()
+        .position(|nato| nato.to_lowercase() == word)
+        .map(|i| {
+            if i < ALPHABET_CNT {
+                (b'A' + i as u8) as char
+            } else {
+                (b'0' + (i - ALPHABET_CNT) as u8) as char
+            }
+        })
+        .or_else(|| {
+            NATO_VARIANTS
+                .iter()
+                .find(|(variant, _)| *variant == word)
+                .map(|(_, c)| *c)
+        })
+}
+
+/// Spells out letters and digits in NATO code words.
+pub fn nato_encode(text: &str) -> String {
+    text.split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter_map(|c| match c.to_ascii_uppercase() {
+                    c @ 'A'..='Z' => Some(NATO[usize::from(c as u8 - b'A')]),
+                    c @ '0'..='9' => Some(NATO[ALPHABET_CNT + usize::from(c as u8 - b'0')]),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join(" / ")
+}
+
+/// Reads a resistor color name, or `None` if it isn't one.
+pub fn resistor_digit(word: &str) -> Option<u8> {
+    let word = word.to_lowercase();
+    RESISTOR_COLORS
+        .iter()
+        .position(|(name, _)| name.to_lowercase() == word)
+        .map(|digit| digit as u8)
+        .or_else(|| {
+            RESISTOR_VARIANTS
+                .iter()
+                .find(|(variant, _)| *variant == word)
+                .map(|(_, digit)| *digit)
+        })
+}
+
+fn bit(entry: &str, index: usize) -> bool {
+    entry.as_bytes()[index] == b'1'
+}
+
+pub fn draw_pigpen(painter: &Painter, rect: Rect, letter: usize, stroke: Stroke) {
+    let entry = PIGPEN[letter];
+    let r = rect.shrink(rect.width() / 6.0);
+    if bit(entry, 5) {
+        let (tip, arms) = if bit(entry, 4) {
+            (r.center_bottom(), [r.left_top(), r.right_top()])
+        } else if bit(entry, 0) {
+            (r.center_top(), [r.left_bottom(), r.right_bottom()])
+        } else if bit(entry, 3) {
+            (r.right_center(), [r.left_top(), r.left_bottom()])
+        } else {
+            (r.left_center(), [r.right_top(), r.right_bottom()])
+        };
+        for arm in arms {
+            painter.line_segment([arm, tip], stroke);
+        }
+    } else {
+        let sides = [
+            (0, [r.left_top(), r.right_top()]),
+            (1, [r.left_top(), r.left_bottom()]),
+            (3, [r.right_top(), r.right_bottom()]),
+            (4, [r.left_bottom(), r.right_bottom()]),
+        ];
+        for (index, side) in sides {
+            if bit(entry, index) {
+                painter.line_segment(side, stroke);
+            }
+        }
+    }
+    if bit(entry, 2) {
+        painter.circle_filled(r.center(), stroke.width * 1.5, stroke.color);
+    }
+}
+
+fn stripes(painter: &Painter, rect: Rect, colors: &[Color32], vertical: bool) {
+    let count = colors.len() as f32;
+    for (i, color) in colors.iter().enumerate() {
+        let (from, to) = (i as f32 / count, (i + 1) as f32 / count);
+        let stripe = if vertical {
+            Rect::from_x_y_ranges(
+                rect.lerp_inside(vec2(from, 0.0)).x..=rect.lerp_inside(vec2(to, 0.0)).x,
+                rect.y_range(),
+            )
+        } else {
+            Rect::from_x_y_ranges(
+                rect.x_range(),
+                rect.lerp_inside(vec2(0.0, from)).y..=rect.lerp_inside(vec2(0.0, to)).y,
+            )
+        };
+        painter.rect_filled(stripe, 0.0, *color);
+    }
+}
+
+fn triangle(painter: &Painter, points: [egui::Pos2; 3], color: Color32) {
+    painter.add(Shape::convex_polygon(points.to_vec(), color, Stroke::NONE));
+}
+
+impl Flag {
+    fn draw(&self, painter: &Painter, rect: Rect) {
+        let at = |x: f32, y: f32| rect.lerp_inside(vec2(x, y));
+        match self {
+            Self::Stripes(colors) => stripes(painter, rect, colors, true),
+            Self::Bands(colors) => stripes(painter, rect, colors, false),
+            Self::Swallowtail(colors) => {
+                let (last, rest) = colors.split_last().expect("a flag has at least one color");
+                let hoist = rest.len() as f32 / colors.len() as f32;
+                stripes(
+                    painter,
+                    Rect::from_min_max(rect.min, at(hoist, 1.0)),
+                    rest,
+                    true,
+                );
+                for (edge, corner) in [(0.0, 0.0), (1.0, 1.0)] {
+                    let points = vec![
+                        at(hoist, edge),
+                        at(1.0, corner),
+                        at(0.75, 0.5),
+                        at(hoist, 0.5),
+                    ];
+                    painter.add(Shape::convex_polygon(points, *last, Stroke::NONE));
+                }
+            }
+            Self::Square(field, square) => {
+                painter.rect_filled(rect, 0.0, *field);
+                let inner = Rect::from_center_size(rect.center(), rect.size() / 2.0);
+                painter.rect_filled(inner, 0.0, *square);
+            }
+            Self::Cross(field, cross) => {
+                painter.rect_filled(rect, 0.0, *field);
+                let width = rect.height() / 5.0;
+                for size in [vec2(rect.width(), width), vec2(width, rect.height())] {
+                    painter.rect_filled(Rect::from_center_size(rect.center(), size), 0.0, *cross);
+                }
+            }
+            Self::Saltire(field, cross) => {
+                painter.rect_filled(rect, 0.0, *field);
+                let stroke = Stroke::new(rect.height() / 5.0, *cross);
+                painter.line_segment([rect.left_top(), rect.right_bottom()], stroke);
+                painter.line_segment([rect.left_bottom(), rect.right_top()], stroke);
+            }
+            Self::Disc(field, disc) => {
+                painter.rect_filled(rect, 0.0, *field);
+                painter.circle_filled(rect.center(), rect.height() / 3.0, *disc);
+            }
+            Self::Diamond(field, diamond) => {
+                painter.rect_filled(rect, 0.0, *field);
+                let points = vec![at(0.5, 0.0), at(1.0, 0.5), at(0.5, 1.0), at(0.0, 0.5)];
+                painter.add(Shape::convex_polygon(points, *diamond, Stroke::NONE));
+            }
+            Self::Checkers(first, second, count) => {
+                let step = 1.0 / *count as f32;
+                for i in 0..count * count {
+                    let (x, y) = ((i % count) as f32 * step, (i / count) as f32 * step);
+                    let color = if (i % count + i / count) % 2 == 0 {
+                        first
+                    } else {
+                        second
+                    };
+                    let cell = Rect::from_min_max(at(x, y), at(x + step, y + step));
+                    painter.rect_filled(cell, 0.0, *color);
+                }
+            }
+            Self::Diagonal(hoist, fly) => {
+                triangle(painter, [at(0.0, 0.0), at(0.0, 1.0), at(1.0, 1.0)], *hoist);
+                triangle(painter, [at(0.0, 0.0), at(1.0, 0.0), at(1.0, 1.0)], *fly);
+            }
+            Self::DiagonalStripes(field, stripe) => {
+                painter.rect_filled(rect, 0.0, *field);
+                let step = rect.width() / 5.0;
+                let stroke = Stroke::new(step / 2.0 * std::f32::consts::SQRT_2, *stripe);
+                for i in 0..=8 {
+                    let x = rect.left() + i as f32 * step;
+                    let ends = [pos2(x, rect.top()), pos2(x - rect.height(), rect.bottom())];
+                    painter.line_segment(ends, stroke);
+                }
+            }
+            Self::Frames(colors) => {
+                let inset = rect.height() / (2.0 * colors.len() as f32);
+                for (i, color) in colors.iter().enumerate() {
+                    painter.rect_filled(rect.shrink(inset * i as f32), 0.0, *color);
+                }
+            }
+            Self::Triangles(colors) => {
+                let corners = [
+                    rect.left_top(),
+                    rect.right_top(),
+                    rect.right_bottom(),
+                    rect.left_bottom(),
+                ];
+                for (i, color) in colors.iter().enumerate() {
+                    triangle(
+                        painter,
+                        [corners[i], corners[(i + 1) % 4], rect.center()],
+                        *color,
+                    );
+                }
+            }
+        }
+    }
+}
+
+pub fn draw_flag(painter: &Painter, rect: Rect, letter: usize) {
+    let painter = painter.with_clip_rect(rect);
+    FLAG_DESIGNS[letter].draw(&painter, rect);
+    painter.rect_stroke(
+        rect,
+        0.0,
+        Stroke::new(1.0, Color32::GRAY),
+        StrokeKind::Inside,
+    );
+}
+
+/// Draws a dancing man. The last letter of a word holds a flag.
+pub fn draw_dancer(painter: &Painter, rect: Rect, letter: usize, flag: bool, stroke: Stroke) {
+    let at = |x: f32, y: f32| rect.lerp_inside(vec2(x, y));
+    let pose = DANCERS[letter].as_bytes();
+    let (neck, hip) = (at(0.5, 0.28), at(0.5, 0.6));
+    painter.circle_stroke(at(0.5, 0.15), rect.width() * 0.13, stroke);
+    painter.line_segment([at(0.5, 0.23), hip], stroke);
+
+    let mut hands = [neck; 2];
+    for (side, hand) in hands.iter_mut().enumerate() {
+        // Points are given for the left side and mirrored for the right.
+        let x = |dx: f32| if side == 0 { 0.5 - dx } else { 0.5 + dx };
+        let arm = match pose[side] {
+            b'U' => vec![at(x(0.2), 0.16), at(x(0.42), 0.04)],
+            b'O' => vec![at(x(0.25), 0.3), at(x(0.48), 0.28)],
+            b'D' => vec![at(x(0.22), 0.4), at(x(0.4), 0.52)],
+            b'H' => vec![at(x(0.38), 0.2), at(x(0.12), 0.04)],
+            _ => vec![at(x(0.4), 0.42), at(x(0.05), 0.56)],
+        };
+        *hand = *arm.last().expect("an arm has a hand");
+        painter.add(Shape::line([vec![neck], arm].concat(), stroke));
+        let leg = match pose[side + 2] {
+            b'S' => vec![at(x(0.1), 0.8), at(x(0.2), 0.98)],
+            b'W' => vec![at(x(0.22), 0.78), at(x(0.45), 0.94)],
+            b'B' => vec![at(x(0.35), 0.78), at(x(0.15), 0.98)],
+            _ => vec![at(x(0.25), 0.84), at(x(0.48), 0.7)],
+        };
+        painter.add(Shape::line([vec![hip], leg].concat(), stroke));
+    }
+
+    if flag {
+        let hand = hands[1];
+        let top = hand - vec2(0.0, rect.height() * 0.25);
+        painter.line_segment([hand, top], stroke);
+        let tip = top + vec2(rect.width() * 0.3, rect.height() * 0.06);
+        let points = vec![top, tip, top + vec2(0.0, rect.height() * 0.12)];
+        painter.add(Shape::convex_polygon(points, stroke.color, Stroke::NONE));
+    }
+}
+
+/// Draws a fingerspelled letter. Points are laid out from the signer's side, with the palm's
+/// top edge at `y = 0`, fingers towards negative `y` and the thumb towards negative `x`.
+pub fn draw_hand(painter: &Painter, rect: Rect, letter: usize, stroke: Stroke) {
+    let hand = &HANDS[letter];
+    let scale = rect.width().min(rect.height()) * 0.6;
+    let turn = egui::emath::Rot2::from_angle(hand.turn * std::f32::consts::FRAC_PI_2);
+    // The hand faces the reader, so its thumb side is on the right.
+    let at = |x: f32, y: f32| rect.center() + turn * vec2(-x - 0.25, y + 0.1) * scale;
+    let line = |points: &[(f32, f32)]| {
+        let points = points.iter().map(|&(x, y)| at(x, y)).collect();
+        painter.add(Shape::line(points, stroke));
+    };
+
+    line(&[
+        (-0.4, 0.0),
+        (-0.4, 0.6),
+        (0.4, 0.6),
+        (0.4, 0.0),
+        (-0.4, 0.0),
+    ]);
+    let meeting = (-0.55, -0.3);
+    let spread = hand
+        .fingers
+        .iter()
+        .filter(|finger| matches!(finger, Finger::Spread))
+        .count() as f32;
+    let mut spread_seen = 0.0;
+    for (i, finger) in hand.fingers.iter().enumerate() {
+        let x = -0.3 + i as f32 * 0.2;
+        let length = if i == 3 { 0.6 } else { 0.8 };
+        match finger {
+            Finger::Up => line(&[(x, 0.0), (x, -length)]),
+            Finger::Spread => {
+                let dx = (spread_seen - (spread - 1.0) / 2.0) * 0.3;
+                spread_seen += 1.0;
+                line(&[(x, 0.0), (x + dx, -length)]);
+            }
+            Finger::Crossed => {
+                let dx = if i == 0 { 0.3 } else { -0.3 };
+                line(&[(x, 0.0), (x + dx, -length)]);
+            }
+            Finger::Hooked => line(&[(x, 0.0), (x, -0.4), (x + 0.08, -0.25)]),
+            Finger::Curled => line(&[
+                (x - 0.08, 0.0),
+                (x - 0.08, -0.12),
+                (x + 0.08, -0.12),
+                (x + 0.08, 0.0),
+            ]),
+            Finger::Rounded => line(&[(x, 0.0), (x, -0.5), (x - 0.3, -0.7)]),
+            Finger::Touching => line(&[(x, 0.0), (x, -0.45), meeting]),
+        }
+    }
+
+    let base = (-0.4, 0.45);
+    match hand.thumb {
+        Thumb::Side => line(&[base, (-0.48, 0.15), (-0.45, -0.1)]),
+        Thumb::Across => line(&[base, (-0.15, 0.25), (0.2, 0.2)]),
+        Thumb::Out => line(&[base, (-0.6, 0.3), (-0.9, 0.25)]),
+        Thumb::Along => line(&[base, (-0.55, 0.1), (-0.5, -0.45)]),
+        Thumb::Meeting => line(&[base, (-0.7, 0.1), meeting]),
+        Thumb::Between => line(&[base, (-0.2, 0.1), (-0.2, -0.3)]),
+        Thumb::Under(count) => {
+            let tip = (-0.4 + count as f32 * 0.2, -0.05);
+            line(&[base, (-0.15, 0.3), tip]);
+        }
+    }
+
+    if let [path @ .., from, to] = hand.trace {
+        let trace = Stroke::new(stroke.width / 2.0, stroke.color.gamma_multiply(0.6));
+        let points = path.iter().chain([from]).map(|&(x, y)| at(x, y)).collect();
+        painter.add(Shape::line(points, trace));
+        let (from, to) = (at(from.0, from.1), at(to.0, to.1));
+        painter.arrow(from, to - from, trace);
+    }
+}
+
+/// Shows every letter with its drawing, like a printed reference chart.
+pub fn chart(ui: &mut egui::Ui, name: &str, size: Vec2, draw: impl Fn(&Painter, Rect, usize)) {
+    egui::Grid::new(name).show(ui, |ui| {
+        for letter in 0..ALPHABET_CNT {
+            ui.vertical_centered(|ui| {
+                let (rect, _) = ui.allocate_exact_size(size, Sense::hover());
+                draw(ui.painter(), rect, letter);
+                ui.label(((b'A' + letter as u8) as char).to_string());
+            });
+            if letter % CHART_COLUMNS == CHART_COLUMNS - 1 {
+                ui.end_row();
+            }
+        }
+    });
+}
+
+/// Draws `text` letter by letter, telling `draw` whether each letter is the last of its word.
+/// Anything that isn't a letter is kept as text.
+pub fn encode(
+    ui: &mut egui::Ui,
+    text: &str,
+    size: Vec2,
+    draw: impl Fn(&Painter, Rect, usize, bool),
+) {
+    ui.horizontal_wrapped(|ui| {
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c.is_ascii_alphabetic() {
+                let (rect, _) = ui.allocate_exact_size(size, Sense::hover());
+                let ends_word = !chars.peek().is_some_and(char::is_ascii_alphabetic);
+                let letter = usize::from(c.to_ascii_uppercase() as u8 - b'A');
+                draw(ui.painter(), rect, letter, ends_word);
+            } else if c.is_whitespace() {
+                ui.add_space(size.x / 2.0);
+            } else {
+                ui.label(RichText::new(c).size(size.y / 2.0));
+            }
+        }
+    });
+}
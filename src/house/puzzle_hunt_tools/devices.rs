@@ -0,0 +1,370 @@
+//! Encodings that come from devices: phone keypads (multi-tap and T9) and computer keyboards.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use egui::{RichText, Sense, pos2, vec2};
+
+use super::super::scratchpad::result_label;
+use super::super::words::words;
+use super::{LARGE_FONT_SIZE, english};
+
+/// Each key of a phone keypad, with the letters printed on it.
+const KEYPAD: [(char, &str); 12] = [
+    ('1', ""),
+    ('2', "ABC"),
+    ('3', "DEF"),
+    ('4', "GHI"),
+    ('5', "JKL"),
+    ('6', "MNO"),
+    ('7', "PQRS"),
+    ('8', "TUV"),
+    ('9', "WXYZ"),
+    ('*', ""),
+    ('0', "␣"),
+    ('#', ""),
+];
+const KEYPAD_COLUMNS: usize = 3;
+
+/// Unshifted keys of a US keyboard, row by row. Dvorak puts its keys in the same places.
+const QWERTY: [&str; 4] = [
+    "`1234567890-=",
+    "qwertyuiop[]\\",
+    "asdfghjkl;'",
+    "zxcvbnm,./",
+];
+const DVORAK: [&str; 4] = [
+    "`1234567890[]",
+    "',.pyfgcrl/=\\",
+    "aoeuidhtns-",
+    ";qjkxbmwvz",
+];
+/// How far each keyboard row is pushed right, in keys.
+const ROW_OFFSETS: [f32; 4] = [0.0, 0.5, 0.75, 1.25];
+/// Up, down, left and right, as (row, column) steps.
+const SHIFTS: [(&str, isize, isize); 4] = [
+    ("Each key one to the left", 0, -1),
+    ("Each key one to the right", 0, 1),
+    ("Each key one row up", -1, 0),
+    ("Each key one row down", 1, 0),
+];
+
+const KEY_SIZE: f32 = 32.0;
+const KEY_GAP: f32 = 3.0;
+const T9_CANDIDATES: usize = 8;
+
+fn keypad_letters(digit: char) -> Option<&'static str> {
+    KEYPAD
+        .iter()
+        .find(|(key, _)| *key == digit)
+        .map(|(_, letters)| *letters)
+}
+
+fn keypad_digit(letter: char) -> Option<char> {
+    let letter = letter.to_ascii_uppercase();
+    KEYPAD
+        .iter()
+        .find(|(_, letters)| letters.contains(letter))
+        .map(|(key, _)| *key)
+}
+
+/// Reads runs of a repeated digit, like `44 33 555 555 666`.
+/// Pressing a key past its last letter wraps around to the first.
+fn multi_tap_decode(input: &str) -> String {
+    let mut result = String::new();
+    for token in input.split_whitespace() {
+        let mut chars = token.chars().peekable();
+        while let Some(digit) = chars.next() {
+            let mut presses = 1;
+            while chars.next_if_eq(&digit).is_some() {
+                presses += 1;
+            }
+            let letter = match (digit, keypad_letters(digit)) {
+                ('0', _) => Some(' '),
+                (_, Some(letters)) if !letters.is_empty() => {
+                    letters.chars().nth((presses - 1) % letters.len())
+                }
+                _ => None,
+            };
+            result.push(letter.unwrap_or('✖'));
+        }
+    }
+    result
+}
+
+fn multi_tap_encode(text: &str) -> String {
+    text.chars()
+        .filter_map(|c| {
+            if c == ' ' {
+                return Some("0".to_owned());
+            }
+            let digit = keypad_digit(c)?;
+            let presses = keypad_letters(digit)?.find(c.to_ascii_uppercase())? + 1;
+            Some(digit.to_string().repeat(presses))
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn t9_digits(word: &str) -> Option<String> {
+    word.chars().map(keypad_digit).collect()
+}
+
+/// Words from the word list by their T9 digits, most common first.
+fn t9_index() -> &'static HashMap<String, Vec<&'static str>> {
+    static INDEX: OnceLock<HashMap<String, Vec<&'static str>>> = OnceLock::new();
+    INDEX.get_or_init(|| {
+        let mut index = HashMap::<String, Vec<&'static str>>::new();
+        for word in words() {
+            if let Some(digits) = t9_digits(word) {
+                index.entry(digits).or_default().push(word);
+            }
+        }
+        index
+    })
+}
+
+fn keyboard_position(layout: &[&str; 4], c: char) -> Option<(usize, usize)> {
+    let c = c.to_ascii_lowercase();
+    layout
+        .iter()
+        .enumerate()
+        .find_map(|(row, keys)| keys.chars().position(|key| key == c).map(|col| (row, col)))
+}
+
+fn keyboard_key(layout: &[&str; 4], row: usize, col: usize) -> Option<char> {
+    layout.get(row)?.chars().nth(col)
+}
+
+fn with_case_of(c: char, original: char) -> char {
+    if original.is_ascii_uppercase() {
+        c.to_ascii_uppercase()
+    } else {
+        c
+    }
+}
+
+/// Moves every key on the keyboard by the given number of rows and columns.
+fn keyboard_shift(text: &str, rows: isize, cols: isize) -> String {
+    text.chars()
+        .map(|c| {
+            let Some((row, col)) = keyboard_position(&QWERTY, c) else {
+                return c;
+            };
+            let shifted = row
+                .checked_add_signed(rows)
+                .zip(col.checked_add_signed(cols))
+                .and_then(|(row, col)| keyboard_key(&QWERTY, row, col));
+            shifted.map_or('✖', |shifted| with_case_of(shifted, c))
+        })
+        .collect()
+}
+
+/// Types the text on a `from` keyboard as if it were a `to` keyboard.
+fn keyboard_remap(text: &str, from: &[&str; 4], to: &[&str; 4]) -> String {
+    text.chars()
+        .map(|c| {
+            keyboard_position(from, c)
+                .and_then(|(row, col)| keyboard_key(to, row, col))
+                .map_or(c, |mapped| with_case_of(mapped, c))
+        })
+        .collect()
+}
+
+/// Draws one key, filled if `highlighted`.
+fn draw_key(ui: &egui::Ui, rect: egui::Rect, label: &str, sublabel: &str, highlighted: bool) {
+    let visuals = ui.visuals();
+    let (fill, text_color) = if highlighted {
+        (visuals.selection.bg_fill, visuals.strong_text_color())
+    } else {
+        (visuals.extreme_bg_color, visuals.text_color())
+    };
+    let painter = ui.painter();
+    painter.rect_filled(rect, 4.0, fill);
+    painter.rect_stroke(
+        rect,
+        4.0,
+        visuals.widgets.noninteractive.bg_stroke,
+        egui::StrokeKind::Inside,
+    );
+    let label_pos = if sublabel.is_empty() {
+        rect.center()
+    } else {
+        rect.center() - vec2(0.0, rect.height() / 6.0)
+    };
+    painter.text(
+        label_pos,
+        egui::Align2::CENTER_CENTER,
+        label,
+        egui::FontId::monospace(16.0),
+        text_color,
+    );
+    painter.text(
+        rect.center() + vec2(0.0, rect.height() / 4.0),
+        egui::Align2::CENTER_CENTER,
+        sublabel,
+        egui::FontId::proportional(10.0),
+        text_color,
+    );
+}
+
+fn ui_keypad_diagram(ui: &mut egui::Ui, highlighted: &str) {
+    let key = vec2(KEY_SIZE * 1.5, KEY_SIZE * 1.25);
+    let rows = KEYPAD.len().div_ceil(KEYPAD_COLUMNS);
+    let (rect, _) = ui.allocate_exact_size(
+        vec2(KEYPAD_COLUMNS as f32, rows as f32) * (key + vec2(KEY_GAP, KEY_GAP)),
+        Sense::hover(),
+    );
+    for (i, (digit, letters)) in KEYPAD.iter().enumerate() {
+        let min = rect.min
+            + vec2((i % KEYPAD_COLUMNS) as f32, (i / KEYPAD_COLUMNS) as f32)
+                * (key + vec2(KEY_GAP, KEY_GAP));
+        let key_rect = egui::Rect::from_min_size(min, key);
+        draw_key(
+            ui,
+            key_rect,
+            &digit.to_string(),
+            letters,
+            highlighted.contains(*digit),
+        );
+    }
+}
+
+fn ui_keyboard_diagram(ui: &mut egui::Ui, layout: &[&str; 4], highlighted: &str) {
+    let highlighted = highlighted.to_lowercase();
+    let step = KEY_SIZE + KEY_GAP;
+    let width = ROW_OFFSETS
+        .iter()
+        .zip(layout)
+        .map(|(offset, keys)| (offset + keys.chars().count() as f32) * step)
+        .fold(0.0, f32::max);
+    let (rect, _) = ui.allocate_exact_size(vec2(width, layout.len() as f32 * step), Sense::hover());
+    for (row, (offset, keys)) in ROW_OFFSETS.iter().zip(layout).enumerate() {
+        for (col, c) in keys.chars().enumerate() {
+            let min = pos2(
+                rect.left() + (offset + col as f32) * step,
+                rect.top() + row as f32 * step,
+            );
+            let key_rect = egui::Rect::from_min_size(min, vec2(KEY_SIZE, KEY_SIZE));
+            draw_key(ui, key_rect, &c.to_string(), "", highlighted.contains(c));
+        }
+    }
+}
+
+fn ui_result_row(ui: &mut egui::Ui, label: &str, result: &str) {
+    ui.label(label);
+    english::score_bar(ui, english::score(result));
+    result_label(ui, RichText::new(result).monospace(), result);
+    ui.end_row();
+}
+
+#[derive(Default)]
+pub struct Devices {
+    dvorak_legends: bool,
+}
+
+impl Devices {
+    pub fn ui_keypad(ui: &mut egui::Ui, input: &mut String) {
+        ui.label("Multi-tap groups (44 33 555), T9 digits (43556), or text to encode");
+        ui.text_edit_singleline(input);
+
+        let highlighted = if input.chars().any(|c| c.is_ascii_alphabetic()) {
+            let multi_tap = multi_tap_encode(input);
+            let t9 = input
+                .split_whitespace()
+                .filter_map(t9_digits)
+                .collect::<Vec<_>>()
+                .join(" ");
+            egui::Grid::new("Keypad encodings").show(ui, |ui| {
+                ui.label("Multi-tap");
+                ui.label(RichText::new(&multi_tap).monospace());
+                ui.end_row();
+                ui.label("T9");
+                ui.label(RichText::new(&t9).monospace());
+                ui.end_row();
+            });
+            multi_tap
+        } else {
+            ui.label("Multi-tap");
+            let decoded = multi_tap_decode(input);
+            result_label(ui, RichText::new(&decoded).size(LARGE_FONT_SIZE), &decoded);
+            Self::ui_t9(ui, input);
+            input.clone()
+        };
+
+        ui.separator();
+        ui_keypad_diagram(ui, &highlighted);
+    }
+
+    fn ui_t9(ui: &mut egui::Ui, input: &str) {
+        let index = t9_index();
+        let tokens = input.split_whitespace().collect::<Vec<_>>();
+        let best = tokens
+            .iter()
+            .map(|digits| {
+                index
+                    .get(*digits)
+                    .and_then(|words| words.first())
+                    .map_or("?", |word| *word)
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        ui.label("T9");
+        result_label(ui, RichText::new(&best).size(LARGE_FONT_SIZE), &best);
+        egui::Grid::new("T9 candidates")
+            .striped(true)
+            .show(ui, |ui| {
+                for digits in tokens {
+                    ui.label(RichText::new(digits).monospace());
+                    let candidates = index.get(digits).map_or_else(
+                        || "No words".to_owned(),
+                        |words| {
+                            let shown = words
+                                .iter()
+                                .take(T9_CANDIDATES)
+                                .copied()
+                                .collect::<Vec<_>>();
+                            let more = words.len().saturating_sub(T9_CANDIDATES);
+                            if more > 0 {
+                                format!("{} (+{more})", shown.join(", "))
+                            } else {
+                                shown.join(", ")
+                            }
+                        },
+                    );
+                    ui.label(candidates);
+                    ui.end_row();
+                }
+            });
+    }
+
+    pub fn ui_keyboard(&mut self, ui: &mut egui::Ui, input: &mut String) {
+        ui.text_edit_singleline(input);
+        egui::Grid::new("Keyboard decodings")
+            .striped(true)
+            .show(ui, |ui| {
+                for (label, rows, cols) in SHIFTS {
+                    ui_result_row(ui, label, &keyboard_shift(input, rows, cols));
+                }
+                ui_result_row(
+                    ui,
+                    "Typed on QWERTY, read as Dvorak",
+                    &keyboard_remap(input, &QWERTY, &DVORAK),
+                );
+                ui_result_row(
+                    ui,
+                    "Typed on Dvorak, read as QWERTY",
+                    &keyboard_remap(input, &DVORAK, &QWERTY),
+                );
+            });
+
+        ui.separator();
+        ui.checkbox(&mut self.dvorak_legends, "Dvorak legends");
+        let layout = if self.dvorak_legends {
+            &DVORAK
+        } else {
+            &QWERTY
+        };
+        ui_keyboard_diagram(ui, layout, input);
+    }
+}
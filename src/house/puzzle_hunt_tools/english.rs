@@ -0,0 +1,138 @@
+//! Rates how much a piece of text looks like English, for ranking candidate decryptions.
+
+use super::ALPHABET_CNT;
+
+const SCORE_BAR_WIDTH: f32 = 80.0;
+
+/// Letter frequencies in English text, in percent.
+const FREQUENCIES: [f32; ALPHABET_CNT] = [
+    8.2, 1.5, 2.8, 4.3, 12.7, 2.2, 2.0, 6.1, 7.0, 0.15, 0.77, 4.0, 2.4, // A-M
+    6.7, 7.5, 1.9, 0.095, 6.0, 6.3, 9.1, 2.8, 0.98, 2.4, 0.15, 2.0, 0.074, // N-Z
+];
+
+/// The most common English bigrams and their frequencies in percent.
+#[rustfmt::skip]
+const BIGRAMS: [(&str, f32); 50] = [
+    ("TH", 3.56), ("HE", 3.07), ("IN", 2.43), ("ER", 2.05), ("AN", 1.99), // 5
+    ("RE", 1.85), ("ON", 1.76), ("AT", 1.49), ("EN", 1.45), ("ND", 1.35), // 10
+    ("TI", 1.34), ("ES", 1.34), ("OR", 1.28), ("TE", 1.20), ("OF", 1.17), // 15
+    ("ED", 1.17), ("IS", 1.13), ("IT", 1.12), ("AL", 1.09), ("AR", 1.07), // 20
+    ("ST", 1.05), ("TO", 1.04), ("NT", 1.04), ("NG", 0.95), ("SE", 0.93), // 25
+    ("HA", 0.93), ("AS", 0.87), ("OU", 0.87), ("IO", 0.83), ("LE", 0.83), // 30
+    ("VE", 0.83), ("CO", 0.79), ("ME", 0.79), ("DE", 0.76), ("HI", 0.76), // 35
+    ("RI", 0.73), ("RO", 0.73), ("IC", 0.70), ("NE", 0.69), ("EA", 0.69), // 40
+    ("RA", 0.69), ("CE", 0.65), ("LI", 0.62), ("CH", 0.60), ("LL", 0.58), // 45
+    ("BE", 0.58), ("MA", 0.57), ("SI", 0.55), ("OM", 0.55), ("UR", 0.54), // 50
+];
+
+/// Short words that show up in almost any English sentence.
+const COMMON_WORDS: [&str; 40] = [
+    "THE", "OF", "AND", "TO", "A", "IN", "IS", "IT", "YOU", "THAT", // 10
+    "HE", "WAS", "FOR", "ON", "ARE", "AS", "WITH", "HIS", "THEY", "I", // 20
+    "AT", "BE", "THIS", "HAVE", "FROM", "OR", "ONE", "HAD", "BY", "BUT", // 30
+    "NOT", "WHAT", "ALL", "WE", "WHEN", "YOUR", "CAN", "THERE", "AN", "MY", // 40
+];
+
+/// The share of words from `COMMON_WORDS` in typical English text.
+const COMMON_WORD_SHARE: f32 = 0.3;
+
+fn letter_index(c: char) -> Option<usize> {
+    c.is_ascii_alphabetic()
+        .then(|| usize::from(c.to_ascii_uppercase() as u8 - b'A'))
+}
+
+/// Cosine similarity between the letter counts and English letter frequencies.
+fn frequency_fit(counts: &[f32; ALPHABET_CNT]) -> f32 {
+    let dot = counts
+        .iter()
+        .zip(FREQUENCIES)
+        .map(|(c, f)| c * f)
+        .sum::<f32>();
+    let norm = |v: &[f32]| v.iter().map(|x| x * x).sum::<f32>().sqrt();
+    dot / (norm(counts) * norm(&FREQUENCIES)).max(f32::EPSILON)
+}
+
+/// How English-like `text` is, from 0 to 1.
+/// Combines letter frequencies with common bigrams and words,
+/// so reversed or shuffled text scores lower.
+pub fn score(text: &str) -> f32 {
+    let mut counts = [0.0; ALPHABET_CNT];
+    let (mut letters, mut others) = (0, 0);
+    let mut pairs = 0;
+    let mut bigram_total = 0.0;
+    let mut previous = None;
+    for c in text.chars() {
+        let Some(index) = letter_index(c) else {
+            if !c.is_whitespace() {
+                others += 1;
+            }
+            previous = None;
+            continue;
+        };
+        counts[index] += 1.0;
+        letters += 1;
+        if let Some(previous) = previous {
+            pairs += 1;
+            let pair = [previous, c.to_ascii_uppercase()];
+            if let Some((_, frequency)) = BIGRAMS
+                .iter()
+                .find(|(bigram, _)| bigram.chars().eq(pair.iter().copied()))
+            {
+                bigram_total += frequency;
+            }
+        }
+        previous = Some(c.to_ascii_uppercase());
+    }
+    if letters == 0 {
+        return 0.0;
+    }
+
+    let bigram_fit = if pairs == 0 {
+        0.0
+    } else {
+        bigram_fit(bigram_total / pairs as f32)
+    };
+    let letter_share = letters as f32 / (letters + others) as f32;
+    let fit = match word_fit(text) {
+        Some(word_fit) => (frequency_fit(&counts) + bigram_fit + word_fit) / 3.0,
+        None => (frequency_fit(&counts) + bigram_fit) / 2.0,
+    };
+    fit * letter_share
+}
+
+/// Maps the mean frequency of the text's bigrams onto 0 to 1,
+/// where typical English text lands around 0.8 and scores keep growing past that.
+fn bigram_fit(mean_frequency: f32) -> f32 {
+    let expected = BIGRAMS.iter().map(|(_, f)| f * f).sum::<f32>() / 100.0;
+    1.0 - (-1.5 * mean_frequency / expected).exp()
+}
+
+/// Share of common words, scaled so that typical prose scores 1.
+/// `None` if the text isn't split into words.
+fn word_fit(text: &str) -> Option<f32> {
+    let words = text
+        .split(|c: char| !c.is_ascii_alphabetic())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>();
+    if words.len() < 2 {
+        return None;
+    }
+    let common = words
+        .iter()
+        .filter(|word| {
+            COMMON_WORDS
+                .iter()
+                .any(|common| common.eq_ignore_ascii_case(word))
+        })
+        .count();
+    Some((common as f32 / words.len() as f32 / COMMON_WORD_SHARE).min(1.0))
+}
+
+/// Draws a 0-to-1 score as a short bar with a percentage.
+pub fn score_bar(ui: &mut egui::Ui, score: f32) {
+    ui.add(
+        egui::ProgressBar::new(score)
+            .desired_width(SCORE_BAR_WIDTH)
+            .text(format!("{:.0}%", score * 100.0)),
+    );
+}
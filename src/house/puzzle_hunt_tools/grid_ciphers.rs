@@ -0,0 +1,411 @@
+//! Ciphers that read letters off a keyed grid (Polybius, tap code, Bifid, Trifid)
+//! or off groups of two-valued features (Baconian).
+
+use egui::RichText;
+
+use super::super::scratchpad::result_label;
+use super::{ALPHABET_CNT, LARGE_FONT_SIZE};
+
+const TRIFID_EXTRA: char = '+';
+
+/// Baconian with I/J and U/V sharing codes, in the order of its 24 codes.
+const BACON_24: &str = "ABCDEFGHIKLMNOPQRSTUWXYZ";
+
+// Baconian criteria are the five letters of a group, with B as Yes.
+pub const BACON: [&str; ALPHABET_CNT] = [
+    "00000", "00001", "00010", "00011", "00100", // ABCDE
+    "00101", "00110", "00111", "01000", "01001", // FGHIJ
+    "01010", "01011", "01100", "01101", "01110", // KLMNO
+    "01111", "10000", "10001", "10010", "10011", // PQRST
+    "10100", "10101", "10110", "10111", "11000", // UVWXY
+    "11001", // Z
+];
+
+/// A keyed alphabet laid out in a `side`-by-`side` square (or cube for Trifid).
+pub struct Square {
+    side: usize,
+    dimensions: u32,
+    cells: Vec<char>,
+}
+
+impl Square {
+    /// Fills `alphabet` into the square, starting with the keyword's letters.
+    pub fn new(keyword: &str, alphabet: &str, side: usize, dimensions: u32) -> Self {
+        let alphabet = alphabet.chars().collect::<Vec<_>>();
+        let mut cells = Vec::new();
+        for c in keyword.chars().chain(alphabet.iter().copied()) {
+            let c = Self::fold(c.to_ascii_uppercase(), &alphabet);
+            if alphabet.contains(&c) && !cells.contains(&c) {
+                cells.push(c);
+            }
+        }
+        Self {
+            side,
+            dimensions,
+            cells,
+        }
+    }
+
+    /// Merges the letters a 25-cell alphabet leaves out into their neighbors.
+    fn fold(c: char, alphabet: &[char]) -> char {
+        match c {
+            'J' if !alphabet.contains(&'J') => 'I',
+            'K' if !alphabet.contains(&'K') => 'C',
+            _ => c,
+        }
+    }
+
+    pub fn polybius(keyword: &str, six_by_six: bool) -> Self {
+        if six_by_six {
+            Self::new(keyword, "ABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789", 6, 2)
+        } else {
+            Self::new(keyword, "ABCDEFGHIKLMNOPQRSTUVWXYZ", 5, 2)
+        }
+    }
+
+    fn tap_code() -> Self {
+        Self::new("", "ABCDEFGHIJLMNOPQRSTUVWXYZ", 5, 2)
+    }
+
+    fn trifid(keyword: &str) -> Self {
+        Self::new(keyword, "ABCDEFGHIJKLMNOPQRSTUVWXYZ+", 3, 3)
+    }
+
+    /// 0-indexed coordinates of `c`, most significant first.
+    pub fn coordinates(&self, c: char) -> Option<Vec<usize>> {
+        let c = Self::fold(c.to_ascii_uppercase(), &self.cells);
+        let mut index = self.cells.iter().position(|&cell| cell == c)?;
+        let mut coordinates = vec![0; self.dimensions as usize];
+        for coordinate in coordinates.iter_mut().rev() {
+            *coordinate = index % self.side;
+            index /= self.side;
+        }
+        Some(coordinates)
+    }
+
+    pub fn at(&self, coordinates: &[usize]) -> Option<char> {
+        if coordinates.iter().any(|&c| c >= self.side) {
+            return None;
+        }
+        let index = coordinates
+            .iter()
+            .fold(0, |index, &c| index * self.side + c);
+        self.cells.get(index).copied()
+    }
+
+    pub fn ui(&self, ui: &mut egui::Ui, name: &str) {
+        self.ui_labeled(ui, name, "123456789");
+    }
+
+    /// Draws the square with `labels` along the top and left instead of numbers.
+    pub fn ui_labeled(&self, ui: &mut egui::Ui, name: &str, labels: &str) {
+        let side = self.side;
+        for layer in 0..self.cells.len().div_ceil(side * side) {
+            if self.dimensions == 3 {
+                ui.label(format!("Layer {}", layer + 1));
+            }
+            egui::Grid::new((name, layer)).show(ui, |ui| {
+                ui.label("");
+                for label in labels.chars().take(side) {
+                    ui.label(RichText::new(label.to_string()).weak());
+                }
+                ui.end_row();
+                let start = layer * side * side;
+                let end = (start + side * side).min(self.cells.len());
+                for (label, cells) in labels.chars().zip(self.cells[start..end].chunks(side)) {
+                    ui.label(RichText::new(label.to_string()).weak());
+                    for cell in cells {
+                        ui.label(RichText::new(cell.to_string()).size(LARGE_FONT_SIZE));
+                    }
+                    ui.end_row();
+                }
+            });
+        }
+    }
+}
+
+/// Reads numbers from the input. Digit strings count as their value,
+/// and any other group (such as `...` or `xxx`) counts as its length.
+fn counts(input: &str, split_digits: bool) -> Vec<usize> {
+    input
+        .split_whitespace()
+        .flat_map(|group| {
+            if group.chars().all(|c| c.is_ascii_digit()) {
+                if split_digits {
+                    group.bytes().map(|b| usize::from(b - b'0')).collect()
+                } else {
+                    vec![group.parse().unwrap_or(0)]
+                }
+            } else {
+                vec![group.chars().count()]
+            }
+        })
+        .collect()
+}
+
+/// Turns 1-indexed coordinate pairs into letters.
+fn read_pairs(square: &Square, numbers: &[usize]) -> String {
+    numbers
+        .chunks(2)
+        .map(|pair| match pair {
+            [row, col] => square
+                .at(&[row.wrapping_sub(1), col.wrapping_sub(1)])
+                .unwrap_or('✖'),
+            _ => '…',
+        })
+        .collect()
+}
+
+/// Bifid and Trifid: write the coordinates of each period in rows,
+/// then read them off across and look the groups up again.
+fn fractionate(square: &Square, text: &str, period: usize, encrypt: bool) -> String {
+    let coordinates = text
+        .chars()
+        .filter_map(|c| square.coordinates(c))
+        .collect::<Vec<_>>();
+    let dimensions = square.dimensions as usize;
+    let period = if period == 0 {
+        coordinates.len().max(1)
+    } else {
+        period
+    };
+
+    let mut result = String::new();
+    for block in coordinates.chunks(period) {
+        let len = block.len();
+        let digits = if encrypt {
+            (0..dimensions)
+                .flat_map(|d| block.iter().map(move |c| c[d]))
+                .collect::<Vec<_>>()
+        } else {
+            let flat = block.concat();
+            (0..len)
+                .flat_map(|i| (0..dimensions).map(move |d| d * len + i))
+                .map(|i| flat[i])
+                .collect::<Vec<_>>()
+        };
+        let group = |i: usize| square.at(&digits[i * dimensions..(i + 1) * dimensions]);
+        result.extend((0..len).filter_map(group));
+    }
+    result
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum BaconFeature {
+    /// The text is already written with A and B (or 0 and 1).
+    Letters,
+    /// Lowercase letters are A, uppercase are B.
+    Case,
+    /// Letters listed in `bacon_marked` are B, others are A.
+    Marked,
+}
+
+pub struct GridCiphers {
+    keyword: String,
+    six_by_six: bool,
+    trifid: bool,
+    period: usize,
+    encrypt: bool,
+    bacon_feature: BaconFeature,
+    bacon_marked: String,
+    bacon_24: bool,
+}
+
+impl Default for GridCiphers {
+    fn default() -> Self {
+        Self {
+            keyword: String::new(),
+            six_by_six: false,
+            trifid: false,
+            period: 5,
+            encrypt: false,
+            bacon_feature: BaconFeature::Letters,
+            bacon_marked: String::new(),
+            bacon_24: false,
+        }
+    }
+}
+
+impl GridCiphers {
+    fn ui_keyword(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Keyword");
+            ui.text_edit_singleline(&mut self.keyword);
+        });
+    }
+
+    fn ui_direction(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut self.encrypt, false, "Decrypt");
+            ui.selectable_value(&mut self.encrypt, true, "Encrypt");
+        });
+    }
+
+    pub fn ui_polybius(&mut self, ui: &mut egui::Ui, input: &mut String) {
+        self.ui_keyword(ui);
+        ui.checkbox(&mut self.six_by_six, "6x6 (with digits)");
+        let square = Square::polybius(&self.keyword, self.six_by_six);
+        ui.label("Coordinates (row then column), or text to encode");
+        ui.text_edit_singleline(input);
+
+        let result = if input.chars().any(|c| c.is_ascii_alphabetic()) {
+            input
+                .chars()
+                .filter_map(|c| square.coordinates(c))
+                .map(|c| format!("{}{}", c[0] + 1, c[1] + 1))
+                .collect::<Vec<_>>()
+                .join(" ")
+        } else {
+            read_pairs(&square, &counts(input, true))
+        };
+        result_label(ui, RichText::new(&result).size(LARGE_FONT_SIZE), &result);
+        ui.separator();
+        square.ui(ui, "Polybius square");
+    }
+
+    pub fn ui_tap_code(ui: &mut egui::Ui, input: &mut String) {
+        let square = Square::tap_code();
+        ui.label("Knocks in groups (like \". ...\" or \"1 3\"), or text to encode. K is C.");
+        ui.text_edit_singleline(input);
+        ui.horizontal(|ui| {
+            if ui.button("✊ Knock").clicked() {
+                input.push('.');
+            }
+            if ui.button("⏸ Pause").clicked() && !input.ends_with(' ') {
+                input.push(' ');
+            }
+            if ui.button("Clear").clicked() {
+                input.clear();
+            }
+        });
+
+        let result = if input.chars().any(|c| c.is_ascii_alphabetic()) {
+            input
+                .chars()
+                .filter_map(|c| square.coordinates(c))
+                .map(|c| format!("{} {}", ".".repeat(c[0] + 1), ".".repeat(c[1] + 1)))
+                .collect::<Vec<_>>()
+                .join("  ")
+        } else {
+            read_pairs(&square, &counts(input, false))
+        };
+        result_label(ui, RichText::new(&result).size(LARGE_FONT_SIZE), &result);
+        ui.separator();
+        square.ui(ui, "Tap code");
+    }
+
+    pub fn ui_bifid(&mut self, ui: &mut egui::Ui, input: &mut String) {
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut self.trifid, false, "Bifid");
+            ui.selectable_value(&mut self.trifid, true, "Trifid");
+        });
+        self.ui_direction(ui);
+        self.ui_keyword(ui);
+        ui.add(egui::Slider::new(&mut self.period, 0..=20).text("Period (0 for none)"));
+        ui.text_edit_singleline(input);
+
+        let square = if self.trifid {
+            Square::trifid(&self.keyword)
+        } else {
+            Square::polybius(&self.keyword, false)
+        };
+        let result = fractionate(&square, input, self.period, self.encrypt);
+        result_label(ui, RichText::new(&result).size(LARGE_FONT_SIZE), &result);
+        ui.separator();
+        let name = if self.trifid {
+            "Trifid cube"
+        } else {
+            "Bifid square"
+        };
+        square.ui(ui, name);
+        if self.trifid {
+            ui.label(format!("{TRIFID_EXTRA} is the 27th symbol."));
+        }
+    }
+
+    pub fn ui_bacon(&mut self, ui: &mut egui::Ui, input: &mut String) {
+        ui.horizontal(|ui| {
+            ui.label("B is");
+            ui.selectable_value(&mut self.bacon_feature, BaconFeature::Letters, "B or 1");
+            ui.selectable_value(&mut self.bacon_feature, BaconFeature::Case, "Uppercase");
+            ui.selectable_value(
+                &mut self.bacon_feature,
+                BaconFeature::Marked,
+                "Marked letters",
+            );
+        });
+        if self.bacon_feature == BaconFeature::Marked {
+            ui.horizontal(|ui| {
+                ui.label("Marked (bold, italic, …) letters");
+                ui.text_edit_singleline(&mut self.bacon_marked);
+            });
+        }
+        ui.checkbox(&mut self.bacon_24, "24 letters (I = J, U = V)");
+        ui.text_edit_multiline(input);
+
+        let bits = self.bacon_bits(input);
+        let groups = bits.chunks(5).map(|group| {
+            let code = group.iter().fold(0, |code, &b| code * 2 + usize::from(b));
+            if group.len() < 5 {
+                '…'
+            } else if self.bacon_24 {
+                BACON_24.chars().nth(code).unwrap_or('✖')
+            } else {
+                super::LETTERS
+                    .chars()
+                    .take(ALPHABET_CNT)
+                    .nth(code)
+                    .unwrap_or('✖')
+            }
+        });
+        ui.label(RichText::new(groups.collect::<String>()).size(LARGE_FONT_SIZE));
+        let pattern = bits
+            .iter()
+            .map(|&b| if b { 'B' } else { 'A' })
+            .collect::<Vec<_>>()
+            .chunks(5)
+            .map(|group| group.iter().collect::<String>())
+            .collect::<Vec<_>>()
+            .join(" ");
+        ui.label(RichText::new(pattern).monospace());
+    }
+
+    fn bacon_bits(&self, input: &str) -> Vec<bool> {
+        let marked = self.bacon_marked.chars().collect::<Vec<_>>();
+        input
+            .chars()
+            .filter_map(|c| match self.bacon_feature {
+                BaconFeature::Letters => match c.to_ascii_uppercase() {
+                    'A' | '0' => Some(false),
+                    'B' | '1' => Some(true),
+                    _ => None,
+                },
+                BaconFeature::Case => c.is_alphabetic().then(|| c.is_uppercase()),
+                BaconFeature::Marked => c.is_alphabetic().then(|| marked.contains(&c)),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Square, fractionate};
+
+    #[test]
+    fn bifid_round_trips() {
+        let square = Square::polybius("BGWKZQPNDSIOAXEFCLUMTHYVR", false);
+        assert_eq!(fractionate(&square, "FLEEATONCE", 0, true), "UAEOLWRINS");
+        assert_eq!(fractionate(&square, "UAEOLWRINS", 0, false), "FLEEATONCE");
+    }
+
+    #[test]
+    fn trifid_round_trips() {
+        let cube = Square::trifid("FELIX MARIE DELASTELLE");
+        let encrypted = fractionate(&cube, "Aide-toi, le ciel t'aidera", 5, true);
+        assert_eq!(encrypted, "FMJFVOISSUFTFPUFEQQC");
+        assert_eq!(
+            fractionate(&cube, &encrypted, 5, false),
+            "AIDETOILECIELTAIDERA"
+        );
+    }
+}
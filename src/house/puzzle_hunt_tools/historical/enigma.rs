@@ -0,0 +1,264 @@
+//! The three-rotor Enigma I, with the army's five rotors, two reflectors and a plugboard.
+
+use egui::RichText;
+
+use super::super::{ALPHABET_CNT, LARGE_FONT_SIZE};
+use crate::house::scratchpad::result_label;
+
+/// Name, wiring and the letter shown in the window when the rotor turns its left neighbor.
+const ROTORS: [(&str, &str, u8); 5] = [
+    ("I", "EKMFLGDQVZNTOWYHXUSPAIBRCJ", b'Q'),
+    ("II", "AJDKSIRUXBLHWTMCQGZNPYFVOE", b'E'),
+    ("III", "BDFHJLCPRTXVZNYEIWGAKMUSQO", b'V'),
+    ("IV", "ESOVPZJAYQUIRHXLNFTGKDCMWB", b'J'),
+    ("V", "VZBRGITYUPSDNHLXAWMJQOFECK", b'Z'),
+];
+const REFLECTORS: [(&str, &str); 2] = [
+    ("UKW-B", "YRUHQSLDPXNGOKMIEBFZCWVJAT"),
+    ("UKW-C", "FVPJIAOYEDRZXWGCTKUQSBNMHL"),
+];
+const SLOT_NAMES: [&str; 3] = ["Left", "Middle", "Right"];
+
+const PLUGBOARD_ERR: &str = "Each letter can only be plugged once";
+const ROTOR_REUSE_ERR: &str = "Each rotor can only be used once";
+
+fn letter(index: usize) -> char {
+    (b'A' + index as u8) as char
+}
+
+/// Lets the drag values take a typed letter.
+fn parse_letter(text: &str) -> Option<f64> {
+    let mut chars = text.trim().chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_ascii_alphabetic() => {
+            Some(f64::from(c.to_ascii_uppercase() as u8 - b'A'))
+        }
+        _ => None,
+    }
+}
+
+fn wire(wiring: &str, index: usize) -> usize {
+    usize::from(wiring.as_bytes()[index] - b'A')
+}
+
+/// Reads letter pairs like `AB CD` into a swap table.
+fn plugboard(pairs: &str) -> Result<[usize; ALPHABET_CNT], &'static str> {
+    let mut table = std::array::from_fn(|i| i);
+    let letters = super::letters(pairs);
+    for pair in letters.chunks(2) {
+        let [a, b] = pair else {
+            break;
+        };
+        let (a, b) = (usize::from(*a as u8 - b'A'), usize::from(*b as u8 - b'A'));
+        if a == b || table[a] != a || table[b] != b {
+            return Err(PLUGBOARD_ERR);
+        }
+        table.swap(a, b);
+    }
+    Ok(table)
+}
+
+pub struct EnigmaMachine {
+    reflector: usize,
+    /// Rotors from left to right, as indices into `ROTORS`.
+    rotors: [usize; 3],
+    /// Ring settings, 0 for A or 01.
+    rings: [usize; 3],
+    /// Starting positions shown in the windows.
+    positions: [usize; 3],
+    plugboard: String,
+}
+
+impl Default for EnigmaMachine {
+    fn default() -> Self {
+        Self {
+            reflector: 0,
+            rotors: [0, 1, 2],
+            rings: [0; 3],
+            positions: [0; 3],
+            plugboard: String::new(),
+        }
+    }
+}
+
+impl EnigmaMachine {
+    fn at_notch(&self, positions: &[usize; 3], slot: usize) -> bool {
+        positions[slot] == usize::from(ROTORS[self.rotors[slot]].2 - b'A')
+    }
+
+    /// Turns the rotors before a key press, including the middle rotor's double step.
+    fn step(&self, positions: &mut [usize; 3]) {
+        let middle_at_notch = self.at_notch(positions, 1);
+        let right_at_notch = self.at_notch(positions, 2);
+        if middle_at_notch {
+            positions[0] = (positions[0] + 1) % ALPHABET_CNT;
+        }
+        if middle_at_notch || right_at_notch {
+            positions[1] = (positions[1] + 1) % ALPHABET_CNT;
+        }
+        positions[2] = (positions[2] + 1) % ALPHABET_CNT;
+    }
+
+    /// Sends a letter through the rotor in `slot`, right to left if `forward`.
+    fn through(&self, positions: &[usize; 3], slot: usize, index: usize, forward: bool) -> usize {
+        let wiring = ROTORS[self.rotors[slot]].1;
+        let shift = (positions[slot] + ALPHABET_CNT - self.rings[slot]) % ALPHABET_CNT;
+        let contact = (index + shift) % ALPHABET_CNT;
+        let output = if forward {
+            wire(wiring, contact)
+        } else {
+            (0..ALPHABET_CNT)
+                .find(|&i| wire(wiring, i) == contact)
+                .unwrap_or(contact)
+        };
+        (output + ALPHABET_CNT - shift) % ALPHABET_CNT
+    }
+
+    /// Enciphers the text, passing anything but letters through without turning the rotors.
+    /// Returns the result and the final rotor positions.
+    fn run(&self, text: &str) -> Result<(String, [usize; 3]), &'static str> {
+        let plugboard = plugboard(&self.plugboard)?;
+        let mut positions = self.positions;
+        let mut result = String::new();
+        for c in text.chars() {
+            if !c.is_ascii_alphabetic() {
+                result.push(c);
+                continue;
+            }
+            self.step(&mut positions);
+            let mut index = plugboard[usize::from(c.to_ascii_uppercase() as u8 - b'A')];
+            for slot in (0..3).rev() {
+                index = self.through(&positions, slot, index, true);
+            }
+            index = wire(REFLECTORS[self.reflector].1, index);
+            for slot in 0..3 {
+                index = self.through(&positions, slot, index, false);
+            }
+            result.push(letter(plugboard[index]));
+        }
+        Ok((result, positions))
+    }
+
+    pub fn ui(&mut self, ui: &mut egui::Ui, input: &mut String) {
+        egui::ComboBox::from_label("Reflector")
+            .selected_text(REFLECTORS[self.reflector].0)
+            .show_ui(ui, |ui| {
+                for (i, (name, _)) in REFLECTORS.iter().enumerate() {
+                    ui.selectable_value(&mut self.reflector, i, *name);
+                }
+            });
+        egui::Grid::new("Enigma rotors").show(ui, |ui| {
+            ui.label("");
+            for name in SLOT_NAMES {
+                ui.label(name);
+            }
+            ui.end_row();
+
+            ui.label("Rotor");
+            for (slot, rotor) in self.rotors.iter_mut().enumerate() {
+                egui::ComboBox::from_id_salt(("Enigma rotor", slot))
+                    .selected_text(ROTORS[*rotor].0)
+                    .show_ui(ui, |ui| {
+                        for (i, (name, _, _)) in ROTORS.iter().enumerate() {
+                            ui.selectable_value(rotor, i, *name);
+                        }
+                    });
+            }
+            ui.end_row();
+
+            ui.label("Ring");
+            for ring in &mut self.rings {
+                ui.add(
+                    egui::DragValue::new(ring)
+                        .range(0..=ALPHABET_CNT - 1)
+                        .custom_formatter(|n, _| {
+                            format!("{:02} ({})", n as usize + 1, letter(n as usize))
+                        })
+                        .custom_parser(|text| {
+                            parse_letter(text).or_else(|| {
+                                let number = text.trim().parse::<f64>().ok()?;
+                                Some(number - 1.0)
+                            })
+                        }),
+                );
+            }
+            ui.end_row();
+
+            ui.label("Start");
+            for position in &mut self.positions {
+                ui.add(
+                    egui::DragValue::new(position)
+                        .range(0..=ALPHABET_CNT - 1)
+                        .custom_formatter(|n, _| letter(n as usize).to_string())
+                        .custom_parser(parse_letter),
+                );
+            }
+            ui.end_row();
+        });
+        ui.horizontal(|ui| {
+            ui.label("Plugboard");
+            ui.text_edit_singleline(&mut self.plugboard);
+        });
+        ui.text_edit_multiline(input);
+
+        let reused = (0..3).any(|i| self.rotors[i + 1..].contains(&self.rotors[i]));
+        let result = if reused {
+            Err(ROTOR_REUSE_ERR)
+        } else {
+            self.run(input)
+        };
+        match result {
+            Ok((output, end)) => {
+                result_label(ui, RichText::new(&output).size(LARGE_FONT_SIZE), &output);
+                ui.horizontal(|ui| {
+                    let window = end.iter().map(|&p| letter(p)).collect::<String>();
+                    ui.label(format!("Rotors end at {window}"));
+                    if ui.button("Continue from here").clicked() {
+                        self.positions = end;
+                        input.clear();
+                    }
+                });
+            }
+            Err(err) => {
+                ui.label(RichText::new(format!("⚠ {err}")).color(ui.visuals().warn_fg_color));
+            }
+        }
+        ui.label(
+            "Enigma is its own inverse: type the ciphertext with the same settings to decrypt.",
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_the_reference_machine() {
+        let machine = EnigmaMachine::default();
+        let (encrypted, positions) = machine.run("AAAAA").unwrap();
+        assert_eq!(encrypted, "BDZGO");
+        assert_eq!(positions, [0, 0, 5]);
+        assert_eq!(machine.run(&encrypted).unwrap().0, "AAAAA");
+    }
+
+    #[test]
+    fn middle_rotor_double_steps() {
+        let machine = EnigmaMachine::default();
+        // A D U, then A D V, A E W and B F X: the middle rotor turns twice in a row.
+        let mut positions = [0, 3, 20];
+        for expected in [[0, 3, 21], [0, 4, 22], [1, 5, 23]] {
+            machine.step(&mut positions);
+            assert_eq!(positions, expected);
+        }
+    }
+
+    #[test]
+    fn plugboard_takes_each_letter_once() {
+        assert_eq!(plugboard("AB AC"), Err(PLUGBOARD_ERR));
+        assert_eq!(
+            plugboard("AB").map(|table| [table[0], table[1]]),
+            Ok([1, 0])
+        );
+    }
+}
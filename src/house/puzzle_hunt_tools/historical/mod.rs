@@ -0,0 +1,465 @@
+//! Classical ciphers that need more than a lookup table:
+//! Playfair, Four-square, ADFGVX, Hill and the Enigma I machine.
+
+mod enigma;
+
+use egui::RichText;
+
+use super::grid_ciphers::Square;
+use super::transposition::{columnar, key_order, read, unread};
+use super::{ALPHABET_CNT, LARGE_FONT_SIZE};
+use crate::house::scratchpad::result_label;
+
+const HILL_KEY_SIZE_ERR: &str = "The key needs 4, 9 or 16 numbers or letters";
+const HILL_KEY_NUMBER_ERR: &str = "The key has something that isn't a whole number";
+const HILL_KEY_INVERSE_ERR: &str = "The key can't be inverted mod 26, so it can't decrypt";
+const MAX_HILL_SIZE: usize = 4;
+const PADDING: char = 'X';
+
+/// Uppercase letters of `text`, dropping everything else.
+fn letters(text: &str) -> Vec<char> {
+    text.chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Pairs up letters, padding the last pair.
+fn digraphs(letters: &[char]) -> Vec<[char; 2]> {
+    letters
+        .chunks(2)
+        .map(|pair| [pair[0], pair.get(1).copied().unwrap_or(PADDING)])
+        .collect()
+}
+
+/// Pairs up letters for Playfair, which can't encrypt a doubled letter.
+/// Doubles are split with X (or Q for a double X), and J is written as I.
+fn playfair_digraphs(letters: &[char]) -> Vec<[char; 2]> {
+    let filler = |c: char| if c == PADDING { 'Q' } else { PADDING };
+    let mut digraphs = Vec::new();
+    let mut pending: Option<char> = None;
+    for c in letters.iter().map(|&c| if c == 'J' { 'I' } else { c }) {
+        match pending.take() {
+            None => pending = Some(c),
+            Some(first) if first == c => {
+                digraphs.push([first, filler(first)]);
+                pending = Some(c);
+            }
+            Some(first) => digraphs.push([first, c]),
+        }
+    }
+    if let Some(first) = pending {
+        digraphs.push([first, filler(first)]);
+    }
+    digraphs
+}
+
+fn playfair(square: &Square, digraphs: &[[char; 2]], encrypt: bool) -> String {
+    let shift = if encrypt { 1 } else { 4 };
+    let mut result = String::new();
+    for &[a, b] in digraphs {
+        let (Some(a), Some(b)) = (square.coordinates(a), square.coordinates(b)) else {
+            continue;
+        };
+        let (a, b) = if a[0] == b[0] {
+            ([a[0], (a[1] + shift) % 5], [b[0], (b[1] + shift) % 5])
+        } else if a[1] == b[1] {
+            ([(a[0] + shift) % 5, a[1]], [(b[0] + shift) % 5, b[1]])
+        } else {
+            ([a[0], b[1]], [b[0], a[1]])
+        };
+        result.extend(square.at(&a));
+        result.extend(square.at(&b));
+    }
+    result
+}
+
+/// Four-square reads plaintext pairs off two plain squares
+/// and ciphertext pairs off two keyed ones, at the other corners of the rectangle.
+fn four_square(keyed: [&Square; 2], digraphs: &[[char; 2]], encrypt: bool) -> String {
+    let plain = Square::polybius("", false);
+    let (from, to) = if encrypt {
+        ([&plain, &plain], keyed)
+    } else {
+        (keyed, [&plain, &plain])
+    };
+    let mut result = String::new();
+    for &[a, b] in digraphs {
+        let (Some(a), Some(b)) = (from[0].coordinates(a), from[1].coordinates(b)) else {
+            continue;
+        };
+        result.extend(to[0].at(&[a[0], b[1]]));
+        result.extend(to[1].at(&[b[0], a[1]]));
+    }
+    result
+}
+
+/// ADFGVX: each letter becomes its row and column label,
+/// then the labels go through a keyed columnar transposition.
+fn adfgvx(square: &Square, labels: &str, key: &str, text: &str, encrypt: bool) -> String {
+    let labels = labels.chars().collect::<Vec<_>>();
+    let columns = key_order(key);
+    if encrypt {
+        let fractionated = text
+            .chars()
+            .filter_map(|c| square.coordinates(c))
+            .flat_map(|c| [labels[c[0]], labels[c[1]]])
+            .collect::<Vec<_>>();
+        if columns.is_empty() {
+            return fractionated.into_iter().collect();
+        }
+        read(&fractionated, &columnar(fractionated.len(), &columns))
+    } else {
+        let ciphertext = text
+            .chars()
+            .map(|c| c.to_ascii_uppercase())
+            .filter(|c| labels.contains(c))
+            .collect::<Vec<_>>();
+        let fractionated = if columns.is_empty() {
+            ciphertext
+        } else {
+            unread(&ciphertext, &columnar(ciphertext.len(), &columns))
+                .chars()
+                .collect()
+        };
+        fractionated
+            .chunks(2)
+            .map(|pair| {
+                let coordinate = |c: &char| labels.iter().position(|label| label == c);
+                match pair {
+                    [row, col] => coordinate(row)
+                        .zip(coordinate(col))
+                        .and_then(|pair| square.at(&<[usize; 2]>::from(pair)))
+                        .unwrap_or('✖'),
+                    _ => '…',
+                }
+            })
+            .collect()
+    }
+}
+
+/// Reads a square key matrix, either as numbers or as letters with A = 0.
+/// Numbers are reduced mod 26.
+fn hill_key(key: &str) -> Result<Vec<Vec<i64>>, &'static str> {
+    let values = if key.chars().any(|c| c.is_ascii_digit()) {
+        key.split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|number| !number.is_empty())
+            .map(|number| number.parse::<i64>().ok())
+            .map(|number| Some(number?.rem_euclid(ALPHABET_CNT as i64)))
+            .collect::<Option<Vec<_>>>()
+            .ok_or(HILL_KEY_NUMBER_ERR)?
+    } else {
+        letters(key)
+            .into_iter()
+            .map(|c| i64::from(c as u8 - b'A'))
+            .collect()
+    };
+    let size = (2..=MAX_HILL_SIZE)
+        .find(|size| size * size == values.len())
+        .ok_or(HILL_KEY_SIZE_ERR)?;
+    Ok(values.chunks(size).map(<[i64]>::to_vec).collect())
+}
+
+fn minor(matrix: &[Vec<i64>], row: usize, col: usize) -> Vec<Vec<i64>> {
+    matrix
+        .iter()
+        .enumerate()
+        .filter(|&(r, _)| r != row)
+        .map(|(_, cells)| {
+            cells
+                .iter()
+                .enumerate()
+                .filter(|&(c, _)| c != col)
+                .map(|(_, &cell)| cell)
+                .collect()
+        })
+        .collect()
+}
+
+fn determinant(matrix: &[Vec<i64>]) -> i64 {
+    if matrix.len() == 1 {
+        return matrix[0][0];
+    }
+    (0..matrix.len())
+        .map(|col| {
+            let sign = if col % 2 == 0 { 1 } else { -1 };
+            sign * matrix[0][col] * determinant(&minor(matrix, 0, col))
+        })
+        .sum()
+}
+
+/// The inverse matrix mod 26, through the adjugate.
+fn inverse_mod_26(matrix: &[Vec<i64>]) -> Option<Vec<Vec<i64>>> {
+    let modulus = ALPHABET_CNT as i64;
+    let det = determinant(matrix).rem_euclid(modulus);
+    let det_inverse = (1..modulus).find(|x| det * x % modulus == 1)?;
+    let size = matrix.len();
+    Some(
+        (0..size)
+            .map(|row| {
+                (0..size)
+                    .map(|col| {
+                        let sign = if (row + col) % 2 == 0 { 1 } else { -1 };
+                        let cofactor = sign * determinant(&minor(matrix, col, row));
+                        (cofactor * det_inverse).rem_euclid(modulus)
+                    })
+                    .collect()
+            })
+            .collect(),
+    )
+}
+
+/// Multiplies each block of letters, as a column vector, by the matrix.
+fn hill(matrix: &[Vec<i64>], text: &str) -> String {
+    let size = matrix.len();
+    let mut values = letters(text)
+        .into_iter()
+        .map(|c| i64::from(c as u8 - b'A'))
+        .collect::<Vec<_>>();
+    while values.len() % size != 0 {
+        values.push(i64::from(PADDING as u8 - b'A'));
+    }
+    values
+        .chunks(size)
+        .flat_map(|block| {
+            matrix.iter().map(move |row| {
+                let value = row.iter().zip(block).map(|(a, b)| a * b).sum::<i64>();
+                (b'A' + value.rem_euclid(ALPHABET_CNT as i64) as u8) as char
+            })
+        })
+        .collect()
+}
+
+fn pairs_string(digraphs: &[[char; 2]]) -> String {
+    digraphs
+        .iter()
+        .map(|pair| pair.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Historical {
+    Playfair,
+    FourSquare,
+    Adfgvx,
+    Hill,
+    Enigma,
+}
+
+pub struct HistoricalCiphers {
+    cipher: Historical,
+    pub(super) input: String,
+    encrypt: bool,
+    keyword: String,
+    second_keyword: String,
+    adfgx: bool,
+    enigma: enigma::EnigmaMachine,
+}
+
+impl Default for HistoricalCiphers {
+    fn default() -> Self {
+        Self {
+            cipher: Historical::Playfair,
+            input: String::new(),
+            encrypt: false,
+            keyword: String::new(),
+            second_keyword: String::new(),
+            adfgx: false,
+            enigma: Default::default(),
+        }
+    }
+}
+
+impl HistoricalCiphers {
+    pub fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal_wrapped(|ui| {
+            ui.selectable_value(&mut self.cipher, Historical::Playfair, "Playfair");
+            ui.selectable_value(&mut self.cipher, Historical::FourSquare, "Four-square");
+            ui.selectable_value(&mut self.cipher, Historical::Adfgvx, "ADFGVX");
+            ui.selectable_value(&mut self.cipher, Historical::Hill, "Hill");
+            ui.selectable_value(&mut self.cipher, Historical::Enigma, "Enigma I");
+        });
+        ui.separator();
+        if self.cipher == Historical::Enigma {
+            self.enigma.ui(ui, &mut self.input);
+            return;
+        }
+
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut self.encrypt, false, "Decrypt");
+            ui.selectable_value(&mut self.encrypt, true, "Encrypt");
+        });
+        match self.cipher {
+            Historical::Playfair => self.ui_playfair(ui),
+            Historical::FourSquare => self.ui_four_square(ui),
+            Historical::Adfgvx => self.ui_adfgvx(ui),
+            Historical::Hill => self.ui_hill(ui),
+            Historical::Enigma => {}
+        }
+    }
+
+    fn ui_keyword(ui: &mut egui::Ui, label: &str, keyword: &mut String) {
+        ui.horizontal(|ui| {
+            ui.label(label);
+            ui.text_edit_singleline(keyword);
+        });
+    }
+
+    fn ui_result(ui: &mut egui::Ui, result: &str) {
+        result_label(ui, RichText::new(result).size(LARGE_FONT_SIZE), result);
+    }
+
+    fn ui_playfair(&mut self, ui: &mut egui::Ui) {
+        Self::ui_keyword(ui, "Keyword", &mut self.keyword);
+        ui.text_edit_multiline(&mut self.input);
+        let square = Square::polybius(&self.keyword, false);
+        let letters = letters(&self.input);
+        let digraphs = if self.encrypt {
+            playfair_digraphs(&letters)
+        } else {
+            digraphs(&letters)
+        };
+        ui.label(RichText::new(pairs_string(&digraphs)).monospace());
+        Self::ui_result(ui, &playfair(&square, &digraphs, self.encrypt));
+        ui.separator();
+        square.ui(ui, "Playfair square");
+    }
+
+    fn ui_four_square(&mut self, ui: &mut egui::Ui) {
+        Self::ui_keyword(ui, "Top right keyword", &mut self.keyword);
+        Self::ui_keyword(ui, "Bottom left keyword", &mut self.second_keyword);
+        ui.text_edit_multiline(&mut self.input);
+        let top_right = Square::polybius(&self.keyword, false);
+        let bottom_left = Square::polybius(&self.second_keyword, false);
+        let digraphs = digraphs(&letters(&self.input));
+        ui.label(RichText::new(pairs_string(&digraphs)).monospace());
+        Self::ui_result(
+            ui,
+            &four_square([&top_right, &bottom_left], &digraphs, self.encrypt),
+        );
+
+        ui.separator();
+        let plain = Square::polybius("", false);
+        egui::Grid::new("Four-square").show(ui, |ui| {
+            ui.vertical(|ui| plain.ui(ui, "Four-square top left"));
+            ui.vertical(|ui| top_right.ui(ui, "Four-square top right"));
+            ui.end_row();
+            ui.vertical(|ui| bottom_left.ui(ui, "Four-square bottom left"));
+            ui.vertical(|ui| plain.ui(ui, "Four-square bottom right"));
+            ui.end_row();
+        });
+    }
+
+    fn ui_adfgvx(&mut self, ui: &mut egui::Ui) {
+        ui.checkbox(&mut self.adfgx, "ADFGX (5×5, I = J)");
+        Self::ui_keyword(ui, "Square keyword", &mut self.keyword);
+        Self::ui_keyword(ui, "Transposition key", &mut self.second_keyword);
+        ui.text_edit_multiline(&mut self.input);
+        let (square, labels) = if self.adfgx {
+            (Square::polybius(&self.keyword, false), "ADFGX")
+        } else {
+            (Square::polybius(&self.keyword, true), "ADFGVX")
+        };
+        let result = adfgvx(
+            &square,
+            labels,
+            &self.second_keyword,
+            &self.input,
+            self.encrypt,
+        );
+        Self::ui_result(ui, &result);
+        ui.separator();
+        square.ui_labeled(ui, "ADFGVX square", labels);
+    }
+
+    fn ui_hill(&mut self, ui: &mut egui::Ui) {
+        Self::ui_keyword(ui, "Key matrix", &mut self.keyword);
+        ui.label("Numbers row by row, or a keyword with A = 0");
+        ui.text_edit_multiline(&mut self.input);
+        let matrix = hill_key(&self.keyword).and_then(|key| {
+            if self.encrypt {
+                Ok(key)
+            } else {
+                inverse_mod_26(&key).ok_or(HILL_KEY_INVERSE_ERR)
+            }
+        });
+        match matrix {
+            Ok(matrix) => {
+                Self::ui_result(ui, &hill(&matrix, &self.input));
+                ui.separator();
+                ui.label(if self.encrypt {
+                    "Key matrix"
+                } else {
+                    "Inverse key matrix"
+                });
+                egui::Grid::new("Hill matrix").show(ui, |ui| {
+                    for row in &matrix {
+                        for cell in row {
+                            ui.label(RichText::new(cell.to_string()).monospace());
+                        }
+                        ui.end_row();
+                    }
+                });
+            }
+            Err(err) => {
+                ui.label(RichText::new(format!("⚠ {err}")).color(ui.visuals().warn_fg_color));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn playfair_hides_the_gold() {
+        let square = Square::polybius("playfair example", false);
+        let digraphs = playfair_digraphs(&letters("Hide the gold in the tree stump"));
+        let encrypted = playfair(&square, &digraphs, true);
+        assert_eq!(encrypted, "BMODZBXDNABEKUDMUIXMMOUVIF");
+        let decrypted = playfair(&square, &super::digraphs(&letters(&encrypted)), false);
+        assert_eq!(decrypted, "HIDETHEGOLDINTHETREXESTUMP");
+    }
+
+    #[test]
+    fn four_square_round_trips() {
+        let top_right = Square::polybius("zgptfoihmuwdrcnykeqaxvsbl", false);
+        let bottom_left = Square::polybius("mfnbdcrhsaxyogvituewlqzkp", false);
+        let keyed = [&top_right, &bottom_left];
+        let encrypted = four_square(keyed, &digraphs(&letters("attack at dawn")), true);
+        assert_eq!(encrypted, "TIYBFHTIZBSY");
+        let decrypted = four_square(keyed, &digraphs(&letters(&encrypted)), false);
+        assert_eq!(decrypted, "ATTACKATDAWN");
+    }
+
+    #[test]
+    fn adfgx_attacks_at_once() {
+        let square = Square::polybius("btalpdhozkqfvsngicuxmrewy", false);
+        let encrypted = adfgvx(&square, "ADFGX", "CARGO", "attack at once", true);
+        assert_eq!(encrypted, "FAXDFADDDGDGFFFAFAXAFAFX");
+        let decrypted = adfgvx(&square, "ADFGX", "CARGO", &encrypted, false);
+        assert_eq!(decrypted, "ATTACKATONCE");
+    }
+
+    #[test]
+    fn hill_round_trips() {
+        let key = hill_key("GYBNQKURP").unwrap();
+        assert_eq!(hill(&key, "ACT"), "POH");
+        assert_eq!(hill(&inverse_mod_26(&key).unwrap(), "POH"), "ACT");
+
+        let key = hill_key("3 3, 2 5").unwrap();
+        assert_eq!(hill_key("-23 29 2 -21"), Ok(key.clone()));
+        let encrypted = hill(&key, "HELP");
+        assert_eq!(encrypted, "HIAT");
+        assert_eq!(hill(&inverse_mod_26(&key).unwrap(), &encrypted), "HELP");
+    }
+
+    #[test]
+    fn hill_rejects_bad_keys() {
+        assert_eq!(hill_key("1 2 3"), Err(HILL_KEY_SIZE_ERR));
+        assert_eq!(hill_key("1 2 3 x"), Err(HILL_KEY_NUMBER_ERR));
+        assert_eq!(inverse_mod_26(&hill_key("2 4 6 8").unwrap()), None);
+    }
+}
@@ -0,0 +1,301 @@
+//! Guesses what an unknown string is by trying every decoder on it
+//! and ranking the results by how well the input fits and how English the output looks.
+
+use egui::RichText;
+
+use super::super::converter::{decode_base64, decode_hex, parse_numbers, printable_score};
+use super::super::scratchpad::result_label;
+use super::{
+    ALPHABET_CNT, BINARY, BRAILLE, HouseGadget, LETTER_CNT, LETTERS, TERNARY, a1z26_letter,
+    english, morse,
+};
+
+const PREVIEW_LEN: usize = 60;
+/// Caesar and reversed text must beat the input's own English score by this much.
+const MIN_IMPROVEMENT: f32 = 0.1;
+const CERTAIN_LENGTH: f32 = 12.0;
+const BRAILLE_BLANK: u32 = 0x2800;
+/// Which bit of a Unicode Braille character holds each dot of a `BRAILLE` entry.
+const BRAILLE_BITS: [u32; 6] = [0, 3, 1, 4, 2, 5];
+
+struct Hypothesis {
+    name: String,
+    confidence: f32,
+    decoded: String,
+}
+
+impl Hypothesis {
+    /// For decoders that only accept a strict format: a full match counts for most of the confidence.
+    fn structural(name: &str, valid_share: f32, decoded: String) -> Self {
+        Self {
+            name: name.to_owned(),
+            confidence: valid_share * (0.6 + 0.4 * english::score(&decoded)),
+            decoded,
+        }
+    }
+
+    /// For decoders that accept almost anything, so only readable output counts.
+    fn bytes(name: &str, bytes: &[u8]) -> Self {
+        let decoded = String::from_utf8_lossy(bytes).into_owned();
+        Self {
+            name: name.to_owned(),
+            confidence: printable_score(&decoded) * (0.4 + 0.6 * english::score(&decoded)),
+            decoded,
+        }
+    }
+}
+
+/// Looks each code up in `table`, returning the text and the share of codes found.
+fn lookup<'a>(table: &[&str], codes: impl Iterator<Item = &'a str>) -> (String, f32) {
+    let (mut text, mut found, mut total) = (String::new(), 0, 0);
+    for code in codes {
+        total += 1;
+        match table.iter().position(|entry| *entry == code) {
+            Some(index) => {
+                text.push(LETTERS.as_bytes()[index] as char);
+                found += 1;
+            }
+            None => text.push('✖'),
+        }
+    }
+    (text, found as f32 / total.max(1) as f32)
+}
+
+/// Splits digit strings into `width`-long codes, either as given or by chunking them all together.
+fn digit_groups(input: &str, digits: &str, width: usize) -> Option<Vec<String>> {
+    if !input
+        .chars()
+        .all(|c| c.is_whitespace() || digits.contains(c))
+    {
+        return None;
+    }
+    let tokens = input.split_whitespace().collect::<Vec<_>>();
+    if !tokens.is_empty() && tokens.iter().all(|token| token.len() == width) {
+        return Some(tokens.into_iter().map(str::to_owned).collect());
+    }
+    let joined = tokens.concat();
+    (!joined.is_empty() && joined.len() % width == 0).then(|| {
+        joined
+            .as_bytes()
+            .chunks(width)
+            .map(|chunk| String::from_utf8_lossy(chunk).into_owned())
+            .collect()
+    })
+}
+
+fn a1z26(input: &str) -> Option<Hypothesis> {
+    let numbers = parse_numbers(input, 10)?;
+    let valid = numbers
+        .iter()
+        .filter(|n| (1..=ALPHABET_CNT as u32).contains(n))
+        .count();
+    let decoded = numbers
+        .iter()
+        .map(|&n| a1z26_letter(n as usize).unwrap_or('✖'))
+        .collect();
+    Some(Hypothesis::structural(
+        "A1Z26",
+        valid as f32 / numbers.len() as f32,
+        decoded,
+    ))
+}
+
+fn morse_code(input: &str) -> Option<Hypothesis> {
+    let decoded = morse::decode(&morse::normalize(input)?);
+    let letters = decoded.chars().filter(|c| !c.is_whitespace()).count();
+    let valid = decoded.chars().filter(char::is_ascii_alphanumeric).count();
+    Some(Hypothesis::structural(
+        "Morse",
+        valid as f32 / letters.max(1) as f32,
+        decoded,
+    ))
+}
+
+fn binary(input: &str) -> Option<Hypothesis> {
+    let groups = digit_groups(input, "01", 5)?;
+    let (decoded, valid_share) = lookup(&BINARY, groups.iter().map(String::as_str));
+    Some(Hypothesis::structural(
+        "Binary (5 bits)",
+        valid_share,
+        decoded,
+    ))
+}
+
+fn binary_ascii(input: &str) -> Option<Hypothesis> {
+    let groups = digit_groups(input, "01", 8)?;
+    let bytes = groups
+        .iter()
+        .map(|group| u8::from_str_radix(group, 2).ok())
+        .collect::<Option<Vec<_>>>()?;
+    Some(Hypothesis::bytes("Binary (8-bit ASCII)", &bytes))
+}
+
+fn ternary(input: &str) -> Option<Hypothesis> {
+    let groups = digit_groups(input, "012", 3)?;
+    let (decoded, valid_share) = lookup(&TERNARY, groups.iter().map(String::as_str));
+    Some(Hypothesis::structural("Ternary", valid_share, decoded))
+}
+
+fn braille(input: &str) -> Option<Hypothesis> {
+    let is_braille = |c: char| (BRAILLE_BLANK..BRAILLE_BLANK + 0x100).contains(&u32::from(c));
+    if !input.chars().any(is_braille) || !input.chars().all(|c| c.is_whitespace() || is_braille(c))
+    {
+        return None;
+    }
+
+    let (mut decoded, mut found, mut total) = (String::new(), 0, 0);
+    let mut numbers = false;
+    for c in input.chars() {
+        let bits = u32::from(c).wrapping_sub(BRAILLE_BLANK);
+        if c.is_whitespace() || bits == 0 {
+            numbers = false;
+            decoded.push(' ');
+            continue;
+        }
+        let pattern = BRAILLE_BITS
+            .iter()
+            .map(|bit| if bits >> bit & 1 == 1 { '1' } else { '0' })
+            .collect::<String>();
+        if pattern == BRAILLE[ALPHABET_CNT] {
+            numbers = true;
+            continue;
+        }
+        let range = if numbers {
+            ALPHABET_CNT + 1..LETTER_CNT
+        } else {
+            0..ALPHABET_CNT
+        };
+        total += 1;
+        match BRAILLE[range.clone()].iter().position(|p| *p == pattern) {
+            Some(index) => {
+                decoded.push(LETTERS.as_bytes()[range.start + index] as char);
+                found += 1;
+            }
+            None => decoded.push('✖'),
+        }
+    }
+    Some(Hypothesis::structural(
+        "Braille",
+        found as f32 / total.max(1) as f32,
+        decoded,
+    ))
+}
+
+/// Short texts look like English by chance, so rewriting ones need some length to be trusted.
+fn length_certainty(input: &str) -> f32 {
+    let letters = input.chars().filter(char::is_ascii_alphabetic).count();
+    (letters as f32 / CERTAIN_LENGTH).min(1.0) * 0.95
+}
+
+/// Shifts each letter `shift` places along the alphabet, keeping its case.
+pub fn caesar_shift(text: &str, shift: u8) -> String {
+    text.chars()
+        .map(|c| {
+            let base = match c {
+                'a'..='z' => b'a',
+                'A'..='Z' => b'A',
+                _ => return c,
+            };
+            (base + (c as u8 - base + shift) % ALPHABET_CNT as u8) as char
+        })
+        .collect()
+}
+
+/// The most English-looking of the 25 nontrivial shifts.
+fn caesar(input: &str) -> Option<Hypothesis> {
+    let (shift, decoded, score) = (1..ALPHABET_CNT as u8)
+        .map(|shift| {
+            let decoded = caesar_shift(input, shift);
+            let score = english::score(&decoded);
+            (shift, decoded, score)
+        })
+        .max_by(|a, b| a.2.total_cmp(&b.2))?;
+    (score > english::score(input) + MIN_IMPROVEMENT).then(|| Hypothesis {
+        name: format!("Caesar (ROT{shift})"),
+        confidence: score * length_certainty(input),
+        decoded,
+    })
+}
+
+fn reversed(input: &str) -> Option<Hypothesis> {
+    let decoded = input.chars().rev().collect::<String>();
+    let score = english::score(&decoded);
+    (score > english::score(input) + MIN_IMPROVEMENT).then(|| Hypothesis {
+        name: "Reversed".to_owned(),
+        confidence: score * length_certainty(input),
+        decoded,
+    })
+}
+
+fn hypotheses(input: &str) -> Vec<Hypothesis> {
+    let mut hypotheses = [
+        a1z26(input),
+        morse_code(input),
+        binary(input),
+        binary_ascii(input),
+        ternary(input),
+        braille(input),
+        decode_hex(input).map(|bytes| Hypothesis::bytes("Hex", &bytes)),
+        decode_base64(input).map(|bytes| Hypothesis::bytes("Base64", &bytes)),
+        caesar(input),
+        reversed(input),
+    ]
+    .into_iter()
+    .flatten()
+    .filter(|hypothesis| hypothesis.confidence > 0.0)
+    .collect::<Vec<_>>();
+    hypotheses.sort_by(|a, b| b.confidence.total_cmp(&a.confidence));
+    hypotheses
+}
+
+pub struct CipherIdentifier {
+    input: String,
+}
+
+impl HouseGadget for CipherIdentifier {
+    fn new() -> Self {
+        Self {
+            input: String::default(),
+        }
+    }
+
+    fn title(&self) -> String {
+        "What is this?".to_owned()
+    }
+
+    fn input(&mut self) -> Option<&mut String> {
+        Some(&mut self.input)
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.label("Paste an unknown string to see what it might be");
+        ui.text_edit_multiline(&mut self.input);
+        let input = self.input.trim();
+        if input.is_empty() {
+            return;
+        }
+
+        let hypotheses = hypotheses(input);
+        ui.separator();
+        if hypotheses.is_empty() {
+            ui.label("No idea, sorry");
+            return;
+        }
+        egui::Grid::new("Hypotheses").striped(true).show(ui, |ui| {
+            for hypothesis in &hypotheses {
+                ui.label(RichText::new(&hypothesis.name).strong());
+                english::score_bar(ui, hypothesis.confidence);
+                let mut preview = hypothesis
+                    .decoded
+                    .chars()
+                    .take(PREVIEW_LEN)
+                    .collect::<String>();
+                if hypothesis.decoded.chars().count() > PREVIEW_LEN {
+                    preview.push('…');
+                }
+                result_label(ui, RichText::new(preview).monospace(), &hypothesis.decoded);
+                ui.end_row();
+            }
+        });
+    }
+}
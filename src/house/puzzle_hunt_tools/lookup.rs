@@ -0,0 +1,749 @@
+//! Lookups for sets that hide letters: the periodic table, Roman numerals and two-letter codes.
+
+use egui::RichText;
+
+use super::copyable;
+
+const MAX_SPELLINGS: usize = 50;
+const MAX_ROMAN: u32 = 3999;
+
+/// Symbols and names, indexed by atomic number minus one.
+pub const ELEMENTS: [(&str, &str); 118] = [
+    ("H", "Hydrogen"),
+    ("He", "Helium"),
+    ("Li", "Lithium"),
+    ("Be", "Beryllium"),
+    ("B", "Boron"),
+    ("C", "Carbon"),
+    ("N", "Nitrogen"),
+    ("O", "Oxygen"),
+    ("F", "Fluorine"),
+    ("Ne", "Neon"),
+    ("Na", "Sodium"),
+    ("Mg", "Magnesium"),
+    ("Al", "Aluminium"),
+    ("Si", "Silicon"),
+    ("P", "Phosphorus"),
+    ("S", "Sulfur"),
+    ("Cl", "Chlorine"),
+    ("Ar", "Argon"),
+    ("K", "Potassium"),
+    ("Ca", "Calcium"),
+    ("Sc", "Scandium"),
+    ("Ti", "Titanium"),
+    ("V", "Vanadium"),
+    ("Cr", "Chromium"),
+    ("Mn", "Manganese"),
+    ("Fe", "Iron"),
+    ("Co", "Cobalt"),
+    ("Ni", "Nickel"),
+    ("Cu", "Copper"),
+    ("Zn", "Zinc"),
+    ("Ga", "Gallium"),
+    ("Ge", "Germanium"),
+    ("As", "Arsenic"),
+    ("Se", "Selenium"),
+    ("Br", "Bromine"),
+    ("Kr", "Krypton"),
+    ("Rb", "Rubidium"),
+    ("Sr", "Strontium"),
+    ("Y", "Yttrium"),
+    ("Zr", "Zirconium"),
+    ("Nb", "Niobium"),
+    ("Mo", "Molybdenum"),
+    ("Tc", "Technetium"),
+    ("Ru", "Ruthenium"),
+    ("Rh", "Rhodium"),
+    ("Pd", "Palladium"),
+    ("Ag", "Silver"),
+    ("Cd", "Cadmium"),
+    ("In", "Indium"),
+    ("Sn", "Tin"),
+    ("Sb", "Antimony"),
+    ("Te", "Tellurium"),
+    ("I", "Iodine"),
+    ("Xe", "Xenon"),
+    ("Cs", "Caesium"),
+    ("Ba", "Barium"),
+    ("La", "Lanthanum"),
+    ("Ce", "Cerium"),
+    ("Pr", "Praseodymium"),
+    ("Nd", "Neodymium"),
+    ("Pm", "Promethium"),
+    ("Sm", "Samarium"),
+    ("Eu", "Europium"),
+    ("Gd", "Gadolinium"),
+    ("Tb", "Terbium"),
+    ("Dy", "Dysprosium"),
+    ("Ho", "Holmium"),
+    ("Er", "Erbium"),
+    ("Tm", "Thulium"),
+    ("Yb", "Ytterbium"),
+    ("Lu", "Lutetium"),
+    ("Hf", "Hafnium"),
+    ("Ta", "Tantalum"),
+    ("W", "Tungsten"),
+    ("Re", "Rhenium"),
+    ("Os", "Osmium"),
+    ("Ir", "Iridium"),
+    ("Pt", "Platinum"),
+    ("Au", "Gold"),
+    ("Hg", "Mercury"),
+    ("Tl", "Thallium"),
+    ("Pb", "Lead"),
+    ("Bi", "Bismuth"),
+    ("Po", "Polonium"),
+    ("At", "Astatine"),
+    ("Rn", "Radon"),
+    ("Fr", "Francium"),
+    ("Ra", "Radium"),
+    ("Ac", "Actinium"),
+    ("Th", "Thorium"),
+    ("Pa", "Protactinium"),
+    ("U", "Uranium"),
+    ("Np", "Neptunium"),
+    ("Pu", "Plutonium"),
+    ("Am", "Americium"),
+    ("Cm", "Curium"),
+    ("Bk", "Berkelium"),
+    ("Cf", "Californium"),
+    ("Es", "Einsteinium"),
+    ("Fm", "Fermium"),
+    ("Md", "Mendelevium"),
+    ("No", "Nobelium"),
+    ("Lr", "Lawrencium"),
+    ("Rf", "Rutherfordium"),
+    ("Db", "Dubnium"),
+    ("Sg", "Seaborgium"),
+    ("Bh", "Bohrium"),
+    ("Hs", "Hassium"),
+    ("Mt", "Meitnerium"),
+    ("Ds", "Darmstadtium"),
+    ("Rg", "Roentgenium"),
+    ("Cn", "Copernicium"),
+    ("Nh", "Nihonium"),
+    ("Fl", "Flerovium"),
+    ("Mc", "Moscovium"),
+    ("Lv", "Livermorium"),
+    ("Ts", "Tennessine"),
+    ("Og", "Oganesson"),
+];
+/// The fifty states and the District of Columbia by postal abbreviation.
+const US_STATES: [(&str, &str); 51] = [
+    ("AK", "Alaska"),
+    ("AL", "Alabama"),
+    ("AR", "Arkansas"),
+    ("AZ", "Arizona"),
+    ("CA", "California"),
+    ("CO", "Colorado"),
+    ("CT", "Connecticut"),
+    ("DC", "District of Columbia"),
+    ("DE", "Delaware"),
+    ("FL", "Florida"),
+    ("GA", "Georgia"),
+    ("HI", "Hawaii"),
+    ("IA", "Iowa"),
+    ("ID", "Idaho"),
+    ("IL", "Illinois"),
+    ("IN", "Indiana"),
+    ("KS", "Kansas"),
+    ("KY", "Kentucky"),
+    ("LA", "Louisiana"),
+    ("MA", "Massachusetts"),
+    ("MD", "Maryland"),
+    ("ME", "Maine"),
+    ("MI", "Michigan"),
+    ("MN", "Minnesota"),
+    ("MO", "Missouri"),
+    ("MS", "Mississippi"),
+    ("MT", "Montana"),
+    ("NC", "North Carolina"),
+    ("ND", "North Dakota"),
+    ("NE", "Nebraska"),
+    ("NH", "New Hampshire"),
+    ("NJ", "New Jersey"),
+    ("NM", "New Mexico"),
+    ("NV", "Nevada"),
+    ("NY", "New York"),
+    ("OH", "Ohio"),
+    ("OK", "Oklahoma"),
+    ("OR", "Oregon"),
+    ("PA", "Pennsylvania"),
+    ("RI", "Rhode Island"),
+    ("SC", "South Carolina"),
+    ("SD", "South Dakota"),
+    ("TN", "Tennessee"),
+    ("TX", "Texas"),
+    ("UT", "Utah"),
+    ("VA", "Virginia"),
+    ("VT", "Vermont"),
+    ("WA", "Washington"),
+    ("WI", "Wisconsin"),
+    ("WV", "West Virginia"),
+    ("WY", "Wyoming"),
+];
+/// ISO 3166-1 alpha-2 codes with everyday country names.
+const COUNTRIES: [(&str, &str); 249] = [
+    ("AD", "Andorra"),
+    ("AE", "United Arab Emirates"),
+    ("AF", "Afghanistan"),
+    ("AG", "Antigua and Barbuda"),
+    ("AI", "Anguilla"),
+    ("AL", "Albania"),
+    ("AM", "Armenia"),
+    ("AO", "Angola"),
+    ("AQ", "Antarctica"),
+    ("AR", "Argentina"),
+    ("AS", "American Samoa"),
+    ("AT", "Austria"),
+    ("AU", "Australia"),
+    ("AW", "Aruba"),
+    ("AX", "Åland Islands"),
+    ("AZ", "Azerbaijan"),
+    ("BA", "Bosnia and Herzegovina"),
+    ("BB", "Barbados"),
+    ("BD", "Bangladesh"),
+    ("BE", "Belgium"),
+    ("BF", "Burkina Faso"),
+    ("BG", "Bulgaria"),
+    ("BH", "Bahrain"),
+    ("BI", "Burundi"),
+    ("BJ", "Benin"),
+    ("BL", "Saint Barthélemy"),
+    ("BM", "Bermuda"),
+    ("BN", "Brunei"),
+    ("BO", "Bolivia"),
+    ("BQ", "Bonaire, Sint Eustatius and Saba"),
+    ("BR", "Brazil"),
+    ("BS", "Bahamas"),
+    ("BT", "Bhutan"),
+    ("BV", "Bouvet Island"),
+    ("BW", "Botswana"),
+    ("BY", "Belarus"),
+    ("BZ", "Belize"),
+    ("CA", "Canada"),
+    ("CC", "Cocos (Keeling) Islands"),
+    ("CD", "Democratic Republic of the Congo"),
+    ("CF", "Central African Republic"),
+    ("CG", "Republic of the Congo"),
+    ("CH", "Switzerland"),
+    ("CI", "Côte d'Ivoire"),
+    ("CK", "Cook Islands"),
+    ("CL", "Chile"),
+    ("CM", "Cameroon"),
+    ("CN", "China"),
+    ("CO", "Colombia"),
+    ("CR", "Costa Rica"),
+    ("CU", "Cuba"),
+    ("CV", "Cabo Verde"),
+    ("CW", "Curaçao"),
+    ("CX", "Christmas Island"),
+    ("CY", "Cyprus"),
+    ("CZ", "Czechia"),
+    ("DE", "Germany"),
+    ("DJ", "Djibouti"),
+    ("DK", "Denmark"),
+    ("DM", "Dominica"),
+    ("DO", "Dominican Republic"),
+    ("DZ", "Algeria"),
+    ("EC", "Ecuador"),
+    ("EE", "Estonia"),
+    ("EG", "Egypt"),
+    ("EH", "Western Sahara"),
+    ("ER", "Eritrea"),
+    ("ES", "Spain"),
+    ("ET", "Ethiopia"),
+    ("FI", "Finland"),
+    ("FJ", "Fiji"),
+    ("FK", "Falkland Islands (Malvinas)"),
+    ("FM", "Micronesia"),
+    ("FO", "Faroe Islands"),
+    ("FR", "France"),
+    ("GA", "Gabon"),
+    ("GB", "United Kingdom"),
+    ("GD", "Grenada"),
+    ("GE", "Georgia"),
+    ("GF", "French Guiana"),
+    ("GG", "Guernsey"),
+    ("GH", "Ghana"),
+    ("GI", "Gibraltar"),
+    ("GL", "Greenland"),
+    ("GM", "Gambia"),
+    ("GN", "Guinea"),
+    ("GP", "Guadeloupe"),
+    ("GQ", "Equatorial Guinea"),
+    ("GR", "Greece"),
+    ("GS", "South Georgia and the South Sandwich Islands"),
+    ("GT", "Guatemala"),
+    ("GU", "Guam"),
+    ("GW", "Guinea-Bissau"),
+    ("GY", "Guyana"),
+    ("HK", "Hong Kong"),
+    ("HM", "Heard Island and McDonald Islands"),
+    ("HN", "Honduras"),
+    ("HR", "Croatia"),
+    ("HT", "Haiti"),
+    ("HU", "Hungary"),
+    ("ID", "Indonesia"),
+    ("IE", "Ireland"),
+    ("IL", "Israel"),
+    ("IM", "Isle of Man"),
+    ("IN", "India"),
+    ("IO", "British Indian Ocean Territory"),
+    ("IQ", "Iraq"),
+    ("IR", "Iran"),
+    ("IS", "Iceland"),
+    ("IT", "Italy"),
+    ("JE", "Jersey"),
+    ("JM", "Jamaica"),
+    ("JO", "Jordan"),
+    ("JP", "Japan"),
+    ("KE", "Kenya"),
+    ("KG", "Kyrgyzstan"),
+    ("KH", "Cambodia"),
+    ("KI", "Kiribati"),
+    ("KM", "Comoros"),
+    ("KN", "Saint Kitts and Nevis"),
+    ("KP", "North Korea"),
+    ("KR", "South Korea"),
+    ("KW", "Kuwait"),
+    ("KY", "Cayman Islands"),
+    ("KZ", "Kazakhstan"),
+    ("LA", "Laos"),
+    ("LB", "Lebanon"),
+    ("LC", "Saint Lucia"),
+    ("LI", "Liechtenstein"),
+    ("LK", "Sri Lanka"),
+    ("LR", "Liberia"),
+    ("LS", "Lesotho"),
+    ("LT", "Lithuania"),
+    ("LU", "Luxembourg"),
+    ("LV", "Latvia"),
+    ("LY", "Libya"),
+    ("MA", "Morocco"),
+    ("MC", "Monaco"),
+    ("MD", "Moldova"),
+    ("ME", "Montenegro"),
+    ("MF", "Saint Martin (French part)"),
+    ("MG", "Madagascar"),
+    ("MH", "Marshall Islands"),
+    ("MK", "North Macedonia"),
+    ("ML", "Mali"),
+    ("MM", "Myanmar"),
+    ("MN", "Mongolia"),
+    ("MO", "Macao"),
+    ("MP", "Northern Mariana Islands"),
+    ("MQ", "Martinique"),
+    ("MR", "Mauritania"),
+    ("MS", "Montserrat"),
+    ("MT", "Malta"),
+    ("MU", "Mauritius"),
+    ("MV", "Maldives"),
+    ("MW", "Malawi"),
+    ("MX", "Mexico"),
+    ("MY", "Malaysia"),
+    ("MZ", "Mozambique"),
+    ("NA", "Namibia"),
+    ("NC", "New Caledonia"),
+    ("NE", "Niger"),
+    ("NF", "Norfolk Island"),
+    ("NG", "Nigeria"),
+    ("NI", "Nicaragua"),
+    ("NL", "Netherlands"),
+    ("NO", "Norway"),
+    ("NP", "Nepal"),
+    ("NR", "Nauru"),
+    ("NU", "Niue"),
+    ("NZ", "New Zealand"),
+    ("OM", "Oman"),
+    ("PA", "Panama"),
+    ("PE", "Peru"),
+    ("PF", "French Polynesia"),
+    ("PG", "Papua New Guinea"),
+    ("PH", "Philippines"),
+    ("PK", "Pakistan"),
+    ("PL", "Poland"),
+    ("PM", "Saint Pierre and Miquelon"),
+    ("PN", "Pitcairn"),
+    ("PR", "Puerto Rico"),
+    ("PS", "Palestine"),
+    ("PT", "Portugal"),
+    ("PW", "Palau"),
+    ("PY", "Paraguay"),
+    ("QA", "Qatar"),
+    ("RE", "Réunion"),
+    ("RO", "Romania"),
+    ("RS", "Serbia"),
+    ("RU", "Russia"),
+    ("RW", "Rwanda"),
+    ("SA", "Saudi Arabia"),
+    ("SB", "Solomon Islands"),
+    ("SC", "Seychelles"),
+    ("SD", "Sudan"),
+    ("SE", "Sweden"),
+    ("SG", "Singapore"),
+    ("SH", "Saint Helena, Ascension and Tristan da Cunha"),
+    ("SI", "Slovenia"),
+    ("SJ", "Svalbard and Jan Mayen"),
+    ("SK", "Slovakia"),
+    ("SL", "Sierra Leone"),
+    ("SM", "San Marino"),
+    ("SN", "Senegal"),
+    ("SO", "Somalia"),
+    ("SR", "Suriname"),
+    ("SS", "South Sudan"),
+    ("ST", "Sao Tome and Principe"),
+    ("SV", "El Salvador"),
+    ("SX", "Sint Maarten (Dutch part)"),
+    ("SY", "Syria"),
+    ("SZ", "Eswatini"),
+    ("TC", "Turks and Caicos Islands"),
+    ("TD", "Chad"),
+    ("TF", "French Southern Territories"),
+    ("TG", "Togo"),
+    ("TH", "Thailand"),
+    ("TJ", "Tajikistan"),
+    ("TK", "Tokelau"),
+    ("TL", "Timor-Leste"),
+    ("TM", "Turkmenistan"),
+    ("TN", "Tunisia"),
+    ("TO", "Tonga"),
+    ("TR", "Turkey"),
+    ("TT", "Trinidad and Tobago"),
+    ("TV", "Tuvalu"),
+    ("TW", "Taiwan"),
+    ("TZ", "Tanzania"),
+    ("UA", "Ukraine"),
+    ("UG", "Uganda"),
+    ("UM", "United States Minor Outlying Islands"),
+    ("US", "United States"),
+    ("UY", "Uruguay"),
+    ("UZ", "Uzbekistan"),
+    ("VA", "Vatican City"),
+    ("VC", "Saint Vincent and the Grenadines"),
+    ("VE", "Venezuela"),
+    ("VG", "British Virgin Islands"),
+    ("VI", "U.S. Virgin Islands"),
+    ("VN", "Vietnam"),
+    ("VU", "Vanuatu"),
+    ("WF", "Wallis and Futuna"),
+    ("WS", "Samoa"),
+    ("YE", "Yemen"),
+    ("YT", "Mayotte"),
+    ("ZA", "South Africa"),
+    ("ZM", "Zambia"),
+    ("ZW", "Zimbabwe"),
+];
+const ROMAN: [(u32, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+/// The atomic number of a symbol, ignoring case.
+pub fn atomic_number(symbol: &str) -> Option<usize> {
+    ELEMENTS
+        .iter()
+        .position(|(s, _)| s.eq_ignore_ascii_case(symbol))
+        .map(|i| i + 1)
+}
+
+/// Up to `MAX_SPELLINGS` ways to split the letters into element symbols, as atomic numbers,
+/// and how many ways there are in total.
+pub fn element_spellings(text: &str) -> (Vec<Vec<usize>>, u64) {
+    let letters = text
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .collect::<String>();
+    let len = letters.len();
+    let symbol_at =
+        |start: usize, width: usize| letters.get(start..start + width).and_then(atomic_number);
+    // ways[i] counts the spellings of letters[i..].
+    let mut ways = vec![0_u64; len + 1];
+    ways[len] = 1;
+    for start in (0..len).rev() {
+        ways[start] = (1..=2)
+            .filter(|&width| symbol_at(start, width).is_some())
+            .map(|width| ways[start + width])
+            .fold(0, u64::saturating_add);
+    }
+
+    let mut spellings = Vec::new();
+    let mut stack = vec![(0, Vec::new())];
+    while let Some((start, numbers)) = stack.pop() {
+        if spellings.len() == MAX_SPELLINGS {
+            break;
+        }
+        if start == len {
+            spellings.push(numbers);
+            continue;
+        }
+        for width in [2, 1] {
+            if let Some(number) = symbol_at(start, width) {
+                if ways[start + width] > 0 {
+                    let mut numbers = numbers.clone();
+                    numbers.push(number);
+                    stack.push((start + width, numbers));
+                }
+            }
+        }
+    }
+    (spellings, if len == 0 { 0 } else { ways[0] })
+}
+
+pub fn to_roman(mut number: u32) -> Option<String> {
+    if !(1..=MAX_ROMAN).contains(&number) {
+        return None;
+    }
+    let mut roman = String::new();
+    for (value, numeral) in ROMAN {
+        while number >= value {
+            roman.push_str(numeral);
+            number -= value;
+        }
+    }
+    Some(roman)
+}
+
+/// Reads a numeral, subtracting any letter worth less than the one after it.
+/// Loose forms like `IIII` or `IC` are read too; compare with `to_roman` to spot them.
+pub fn from_roman(text: &str) -> Option<u32> {
+    let values = text
+        .chars()
+        .map(|c| match c.to_ascii_uppercase() {
+            'I' => Some(1),
+            'V' => Some(5),
+            'X' => Some(10),
+            'L' => Some(50),
+            'C' => Some(100),
+            'D' => Some(500),
+            'M' => Some(1000),
+            _ => None,
+        })
+        .collect::<Option<Vec<u32>>>()?;
+    if values.is_empty() {
+        return None;
+    }
+    let mut total = 0_i64;
+    for (i, &value) in values.iter().enumerate() {
+        if values.get(i + 1).is_some_and(|&next| next > value) {
+            total -= i64::from(value);
+        } else {
+            total += i64::from(value);
+        }
+    }
+    u32::try_from(total).ok().filter(|&total| total > 0)
+}
+
+fn by_code(table: &[(&str, &'static str)], code: &str) -> Option<&'static str> {
+    table
+        .iter()
+        .find(|(c, _)| c.eq_ignore_ascii_case(code))
+        .map(|&(_, name)| name)
+}
+
+fn by_name<'a>(table: &'a [(&'a str, &'a str)], query: &str) -> Vec<&'a (&'a str, &'a str)> {
+    let query = query.to_lowercase();
+    table
+        .iter()
+        .filter(|(_, name)| name.to_lowercase().contains(&query))
+        .collect()
+}
+
+fn unknown(ui: &mut egui::Ui) {
+    ui.label(RichText::new("✖").color(ui.visuals().warn_fg_color));
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Mode {
+    Elements,
+    Roman,
+    Codes,
+}
+
+pub struct Lookup {
+    mode: Mode,
+    pub(super) input: String,
+}
+
+impl Default for Lookup {
+    fn default() -> Self {
+        Self {
+            mode: Mode::Elements,
+            input: String::new(),
+        }
+    }
+}
+
+impl Lookup {
+    pub fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut self.mode, Mode::Elements, "Periodic table");
+            ui.selectable_value(&mut self.mode, Mode::Roman, "Roman numerals");
+            ui.selectable_value(&mut self.mode, Mode::Codes, "State / country codes");
+        });
+        ui.label(match self.mode {
+            Mode::Elements => "Atomic numbers or letters to spell with element symbols",
+            Mode::Roman => "Numbers or numerals, separated by spaces",
+            Mode::Codes => "Two-letter codes, or a name per line",
+        });
+        ui.text_edit_multiline(&mut self.input);
+        ui.separator();
+
+        match self.mode {
+            Mode::Elements => ui_elements(ui, &self.input),
+            Mode::Roman => ui_roman(ui, &self.input),
+            Mode::Codes => ui_codes(ui, &self.input),
+        }
+    }
+}
+
+fn ui_elements(ui: &mut egui::Ui, input: &str) {
+    let numbers = input
+        .split(|c: char| !c.is_ascii_digit())
+        .filter_map(|number| number.parse::<usize>().ok())
+        .collect::<Vec<_>>();
+    if !numbers.is_empty() {
+        let element = |number: usize| number.checked_sub(1).and_then(|i| ELEMENTS.get(i));
+        let symbols = numbers
+            .iter()
+            .map(|&number| element(number).map_or("✖", |(symbol, _)| symbol))
+            .collect::<String>();
+        ui.heading("Atomic numbers");
+        copyable(ui, &symbols);
+        egui::Grid::new("Atomic numbers")
+            .striped(true)
+            .show(ui, |ui| {
+                for number in numbers {
+                    ui.label(number.to_string());
+                    if let Some((symbol, name)) = element(number) {
+                        ui.label(*symbol);
+                        ui.label(*name);
+                    } else {
+                        unknown(ui);
+                    }
+                    ui.end_row();
+                }
+            });
+    }
+
+    if !input.chars().any(|c| c.is_ascii_alphabetic()) {
+        return;
+    }
+    ui.heading("Element spellings");
+    let (spellings, total) = element_spellings(input);
+    match total {
+        0 => {
+            ui.label("These letters can't be spelled with element symbols.");
+            return;
+        }
+        1 => ui.label("1 spelling"),
+        _ if total > spellings.len() as u64 => {
+            ui.label(format!("{total} spellings, showing {}", spellings.len()))
+        }
+        _ => ui.label(format!("{total} spellings")),
+    };
+    egui::Grid::new("Element spellings")
+        .striped(true)
+        .show(ui, |ui| {
+            for numbers in spellings {
+                let symbols = numbers
+                    .iter()
+                    .map(|&number| ELEMENTS[number - 1].0)
+                    .collect::<Vec<_>>();
+                copyable(ui, &symbols.join(" "));
+                let numbers = numbers.iter().map(ToString::to_string).collect::<Vec<_>>();
+                copyable(ui, &numbers.join(" "));
+                ui.end_row();
+            }
+        });
+}
+
+fn ui_roman(ui: &mut egui::Ui, input: &str) {
+    egui::Grid::new("Roman numerals")
+        .striped(true)
+        .show(ui, |ui| {
+            for token in input.split_whitespace() {
+                ui.label(token);
+                if let Ok(number) = token.parse::<u32>() {
+                    match to_roman(number) {
+                        Some(roman) => copyable(ui, &roman),
+                        None => {
+                            ui.label(
+                                RichText::new(format!("✖ Only 1 to {MAX_ROMAN}"))
+                                    .color(ui.visuals().warn_fg_color),
+                            );
+                        }
+                    }
+                } else if let Some(number) = from_roman(token) {
+                    copyable(ui, &number.to_string());
+                    let usual =
+                        to_roman(number).filter(|roman| *roman != token.to_ascii_uppercase());
+                    if let Some(roman) = usual {
+                        ui.weak(format!("usually {roman}"));
+                    }
+                } else {
+                    unknown(ui);
+                }
+                ui.end_row();
+            }
+        });
+}
+
+fn ui_codes(ui: &mut egui::Ui, input: &str) {
+    egui::Grid::new("Codes").striped(true).show(ui, |ui| {
+        ui.label("Code");
+        ui.label("US state");
+        ui.label("Country");
+        ui.end_row();
+        for line in input.lines().map(str::trim).filter(|line| !line.is_empty()) {
+            let codes = line
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|code| !code.is_empty())
+                .collect::<Vec<_>>();
+            if codes.iter().all(|code| code.len() == 2) {
+                for code in codes {
+                    ui.label(code.to_ascii_uppercase());
+                    let state = by_code(&US_STATES, code);
+                    let country = by_code(&COUNTRIES, code);
+                    for name in [state, country] {
+                        match name {
+                            Some(name) => ui.label(name),
+                            None => ui.weak("-"),
+                        };
+                    }
+                    ui.end_row();
+                }
+                continue;
+            }
+            let states = by_name(&US_STATES, line);
+            let countries = by_name(&COUNTRIES, line);
+            if states.is_empty() && countries.is_empty() {
+                unknown(ui);
+                ui.label(line);
+                ui.end_row();
+            }
+            for (code, name) in states {
+                copyable(ui, code);
+                ui.label(*name);
+                ui.end_row();
+            }
+            for (code, name) in countries {
+                copyable(ui, code);
+                ui.label("");
+                ui.label(*name);
+                ui.end_row();
+            }
+        }
+    });
+}
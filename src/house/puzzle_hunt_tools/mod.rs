@@ -0,0 +1,831 @@
+mod audio;
+mod charts;
+mod devices;
+pub mod english;
+mod grid_ciphers;
+mod historical;
+mod identifier;
+mod lookup;
+mod morse;
+mod morse_recording;
+mod rebus;
+mod sets;
+mod transposition;
+mod workbench;
+
+use egui::{Color32, RichText, vec2};
+
+use super::bus::Message;
+use super::scratchpad::{result_label, send_button};
+use super::{HouseGadget, color_emoji};
+pub use identifier::CipherIdentifier;
+use std::fmt::Write as _;
+use strum::IntoEnumIterator as _;
+use strum_macros::EnumIter;
+pub use workbench::TextWorkbench;
+
+const LETTERS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ#0123456789";
+const ALPHABET_CNT: usize = 26;
+const LETTER_CNT: usize = 37;
+const LARGE_FONT_SIZE: f32 = 24.0;
+const LARGE_BUTTON_SIZE: f32 = 35.0;
+
+const BINARY: [&str; ALPHABET_CNT] = [
+    "00001", "00010", "00011", "00100", "00101", // ABCDE
+    "00110", "00111", "01000", "01001", "01010", // FGHIJ
+    "01011", "01100", "01101", "01110", "01111", // KLMNO
+    "10000", "10001", "10010", "10011", "10100", // PQRST
+    "10101", "10110", "10111", "11000", "11001", // UVWXY
+    "11010", // Z
+];
+const BRAILLE: [&str; LETTER_CNT] = [
+    "100000", "101000", "110000", "110100", "100100", // ABCDE
+    "111000", "111100", "101100", "011000", "011100", // FGHIJ
+    "100010", "101010", "110010", "110110", "100110", // KLMNO
+    "111010", "111110", "101110", "011010", "011110", // PQRST
+    "100011", "101011", "011101", "110011", "110111", // UVWXY
+    "100111", "010111", // Z#
+    "011100", "100000", "101000", "110000", "110100", // 01234
+    "100100", "111000", "111100", "101100", "011000", // 56789
+];
+const MORSE: [&str; LETTER_CNT] = [
+    "12000", "21110", "21210", "21100", "10000", // ABCDE
+    "11210", "22100", "11110", "11000", "12220", // FGHIJ
+    "21200", "12110", "22000", "21000", "22200", // KLMNO
+    "12210", "22120", "12100", "11100", "20000", // PQRST
+    "11200", "11120", "12200", "21120", "21220", // UVWXY
+    "22110", "99999", // Z#
+    "22222", "12222", "11222", "11122", "11112", // 01234
+    "11111", "21111", "22111", "22211", "22221", // 56789
+];
+const SEMAPHORE: [&str; LETTER_CNT] = [
+    "00000110", "00010010", "10000010", "01000010", // ABCD
+    "00100010", "00001010", "00000011", "00010100", // EFGH
+    "10000100", "01001000", "01000100", "00100100", // IJKL
+    "00001100", "00000101", "10010000", "01010000", // MNOP
+    "00110000", "00011000", "00010001", "11000000", // QRST
+    "10100000", "01000001", "00101000", "00100001", // UVWX
+    "10001000", "00001001", "01100000", // YZ#
+    "01001000", "00000110", "00010010", "10000010", // 0123
+    "01000010", "00100010", "00001010", "00000011", // 4567
+    "00010100", "10000100", // 89
+];
+const TERNARY: [&str; ALPHABET_CNT] = [
+    "001", "002", "010", "011", "012", // ABCDE
+    "020", "021", "022", "100", "101", // FGHIJ
+    "102", "110", "111", "112", "120", // KLMNO
+    "121", "122", "200", "201", "202", // PQRST
+    "210", "211", "212", "220", "221", // UVWXY
+    "212", // Z
+];
+
+const INDEXING_WHITESPACE_ERR: &str = "Please separate the indices by whitespaces only";
+const OOB_ERR: &str = "Out of bounds";
+
+/// The letter at `num` in the alphabet, counting from 1.
+fn a1z26_letter(num: usize) -> Option<char> {
+    let index = num.checked_sub(1).filter(|&index| index < ALPHABET_CNT)?;
+    Some(LETTERS.as_bytes()[index] as char)
+}
+
+#[derive(Default)]
+struct Indexing {
+    input: String,
+    indices: String,
+    use_0_indexing: bool,
+}
+
+impl Indexing {
+    /// Returns (input length, extracted string).
+    fn get(&self) -> (usize, String) {
+        let alphabets = self
+            .input
+            .chars()
+            .filter(|c| c.is_alphabetic())
+            .collect::<Vec<_>>();
+        let input_length = alphabets.len();
+
+        let Ok(indices) = self
+            .indices
+            .split_whitespace()
+            .map(|num| num.parse::<usize>())
+            .collect::<Result<Vec<_>, _>>()
+        else {
+            return (input_length, INDEXING_WHITESPACE_ERR.to_owned());
+        };
+
+        let Some(extracted) = indices
+            .into_iter()
+            .map(|index| {
+                let index = if self.use_0_indexing {
+                    Some(index)
+                } else {
+                    index.checked_sub(1)
+                }?;
+                alphabets.get(index).copied()
+            })
+            .collect::<Option<String>>()
+        else {
+            return (input_length, OOB_ERR.to_owned());
+        };
+
+        (input_length, extracted.to_uppercase())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, EnumIter)]
+enum CipherMode {
+    A1Z26,
+    Asl,
+    Bacon,
+    Bifid,
+    Binary,
+    Braille,
+    DancingMen,
+    Flags,
+    Keyboard,
+    Keypad,
+    Morse,
+    Nato,
+    Pigpen,
+    Polybius,
+    Resistor,
+    Semaphore,
+    TapCode,
+    Ternary,
+}
+
+impl CipherMode {
+    fn name(&self) -> &str {
+        match self {
+            Self::A1Z26 => "🆎 A1Z26",
+            Self::Asl => "🤟 ASL",
+            Self::Bacon => "🥓 Baconian",
+            Self::Bifid => "🔀 Bifid / Trifid",
+            Self::Binary => "🔟 Binary",
+            Self::Braille => "✋ Braille",
+            Self::DancingMen => "🕺 Dancing Men",
+            Self::Flags => "⚓ Maritime flags",
+            Self::Keyboard => "⌨ Keyboard",
+            Self::Keypad => "📱 Phone keypad",
+            Self::Morse => "〰 Morse",
+            Self::Nato => "📻 NATO",
+            Self::Pigpen => "🐷 Pigpen",
+            Self::Polybius => "🔢 Polybius",
+            Self::Resistor => "🌈 Resistor",
+            Self::Semaphore => "🚩 Semaphore",
+            Self::TapCode => "✊ Tap code",
+            Self::Ternary => "３ Ternary",
+        }
+    }
+
+    fn answer_list(&self) -> &[&str] {
+        match self {
+            Self::Bacon => &grid_ciphers::BACON,
+            Self::Binary => &BINARY,
+            Self::Braille => &BRAILLE,
+            Self::Flags => &charts::FLAGS,
+            Self::Morse => &MORSE,
+            Self::Pigpen => &charts::PIGPEN,
+            Self::Semaphore => &SEMAPHORE,
+            Self::Ternary => &TERNARY,
+            Self::A1Z26
+            | Self::Asl
+            | Self::Bifid
+            | Self::DancingMen
+            | Self::Keyboard
+            | Self::Keypad
+            | Self::Nato
+            | Self::Polybius
+            | Self::Resistor
+            | Self::TapCode => unreachable!("{self:?} doesn't support wildcards"),
+        }
+    }
+
+    /// Criteria that stand for a color are drawn in that color.
+    fn criterion_color(&self, index: usize) -> Option<Color32> {
+        match self {
+            Self::Flags => Some(charts::FLAG_COLORS[index]),
+            _ => None,
+        }
+    }
+
+    fn has_two(&self) -> bool {
+        matches!(self, Self::Morse | Self::Ternary)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum CipherCriterion {
+    Blank,
+    Yes,
+    No,
+    Two, // 2 for Ternary, dash for Morse
+}
+
+impl CipherCriterion {
+    fn label(&self, mode: CipherMode, index: usize) -> char {
+        match (self, mode) {
+            (Self::Blank, _) => ' ',
+            (Self::No, CipherMode::Bacon) => 'A',
+            (Self::Yes, CipherMode::Bacon) => 'B',
+            (Self::No, CipherMode::Binary | CipherMode::Ternary) => '0',
+            (Self::Yes, CipherMode::Binary | CipherMode::Ternary) => '1',
+            (Self::Two, CipherMode::Ternary) => '2',
+            (Self::Two, CipherMode::Morse) => '—',
+            (Self::Yes, CipherMode::Morse) => '⚫',
+            (Self::No, _) => '✖',
+            (_, CipherMode::Braille) => '⏺',
+            (_, CipherMode::Flags) => '✔',
+            (_, CipherMode::Pigpen) => charts::PIGPEN_LABELS[index],
+            (_, CipherMode::Semaphore) => '🚩',
+            _ => unreachable!(),
+        }
+    }
+
+    fn matches(&self, ans: char, exact: bool) -> bool {
+        if ans == '9' {
+            // e.g. in MORSE, # is "99999" since it doesn't exist
+            return false;
+        }
+
+        let choice = if exact && matches!(self, Self::Blank) {
+            Self::No
+        } else {
+            *self
+        };
+        matches!(
+            (ans, choice),
+            (_, Self::Blank) | ('0', Self::No) | ('1', Self::Yes) | ('2', Self::Two)
+        )
+    }
+
+    fn left_click(&mut self, mode: CipherMode) {
+        *self = match self {
+            Self::Blank | Self::No => Self::Yes,
+            Self::Yes => {
+                if mode.has_two() {
+                    Self::Two
+                } else {
+                    Self::Blank
+                }
+            }
+            Self::Two => Self::Blank,
+        };
+    }
+
+    fn right_click(&mut self) {
+        *self = match self {
+            Self::Blank | Self::Yes | Self::Two => Self::No,
+            Self::No => Self::Blank,
+        };
+    }
+}
+
+pub struct Cipher {
+    mode: CipherMode,
+    use_numbers: bool,
+    criteria: [CipherCriterion; 10],
+    input: String,
+    morse: morse::MorseStation,
+    grid: grid_ciphers::GridCiphers,
+    devices: devices::Devices,
+}
+
+impl Cipher {
+    fn match_letter(&self, num: usize, exact: bool) -> bool {
+        let answer = self.mode.answer_list()[num];
+        answer
+            .chars()
+            .zip(&self.criteria)
+            .all(|(ans, crit)| crit.matches(ans, exact))
+    }
+
+    fn match_result(&self, exact: bool) -> impl Iterator<Item = char> + '_ {
+        LETTERS
+            .char_indices()
+            .take(if self.use_numbers { 37 } else { 26 })
+            .filter(move |(i, _)| self.match_letter(*i, exact))
+            .map(|(_, letter)| letter)
+    }
+
+    fn ui_with_wildcard(
+        &mut self,
+        ui: &mut egui::Ui,
+        name: &str,
+        newlines: &[usize],
+        special_labels: &[(usize, char)],
+    ) {
+        let total = newlines.last().unwrap() + 1;
+        egui::Grid::new(name).show(ui, |ui| {
+            for (i, crit) in self.criteria[..total].iter_mut().enumerate() {
+                let mut text = RichText::new(crit.label(self.mode, i)).size(LARGE_FONT_SIZE);
+                let fill = self.mode.criterion_color(i);
+                if let Some(color) = fill {
+                    let contrast = if color.intensity() < 0.5 {
+                        Color32::WHITE
+                    } else {
+                        Color32::BLACK
+                    };
+                    text = text.color(contrast);
+                }
+                let mut button =
+                    egui::Button::new(text).min_size(vec2(LARGE_BUTTON_SIZE, LARGE_BUTTON_SIZE));
+                if let Some(color) = fill {
+                    button = button.fill(color);
+                }
+                let response = ui.add(button);
+                if response.clicked() {
+                    crit.left_click(self.mode);
+                }
+                if response.secondary_clicked() {
+                    crit.right_click();
+                }
+
+                if let Some((_, c)) = special_labels.iter().find(|(index, _)| *index == i) {
+                    ui.label(RichText::new(*c).size(LARGE_FONT_SIZE));
+                }
+                if newlines.contains(&i) {
+                    ui.end_row();
+                }
+            }
+        });
+
+        ui.separator();
+
+        ui.heading("Exact match");
+        let result = self
+            .match_result(true)
+            .fold(String::new(), |mut s, letter| {
+                write!(&mut s, "{letter} ").unwrap();
+                s
+            });
+        ui.horizontal(|ui| {
+            result_label(ui, result.as_str(), result.trim_end());
+            send_button(ui, result.trim_end());
+        });
+
+        ui.heading("Blank match");
+        let result = self
+            .match_result(false)
+            .fold(String::new(), |mut s, letter| {
+                write!(&mut s, "{letter} ").unwrap();
+                s
+            });
+        ui.horizontal(|ui| {
+            result_label(ui, result.as_str(), result.trim_end());
+            send_button(ui, result.trim_end());
+        });
+    }
+
+    fn ui_a1z26(&mut self, ui: &mut egui::Ui) {
+        ui.text_edit_singleline(&mut self.input);
+        let mut answer = String::new();
+        let mut warn_oob = false;
+        for word in self.input.split_whitespace() {
+            let Ok(num) = word.parse::<usize>() else {
+                write!(&mut answer, "{word} ").unwrap();
+                continue;
+            };
+            let Some(letter) = a1z26_letter(num) else {
+                write!(&mut answer, "✖ ").unwrap();
+                warn_oob = true;
+                continue;
+            };
+            write!(&mut answer, "{letter} ").unwrap();
+        }
+
+        result_label(ui, answer.as_str(), answer.trim_end());
+        if warn_oob {
+            ui.label(
+                RichText::new("⚠ There is an out-of-bound number")
+                    .color(ui.visuals().warn_fg_color),
+            );
+        }
+    }
+
+    fn ui_asl(&mut self, ui: &mut egui::Ui) {
+        let stroke = egui::Stroke::new(2.0, ui.visuals().text_color());
+        let draw = |painter: &egui::Painter, rect, letter| {
+            charts::draw_hand(painter, rect, letter, stroke);
+        };
+        ui.text_edit_singleline(&mut self.input);
+        charts::encode(
+            ui,
+            &self.input,
+            charts::HAND_SIZE,
+            |painter, rect, letter, _| {
+                draw(painter, rect, letter);
+            },
+        );
+        ui.separator();
+        ui.label("Hands are seen from the front, palm out. Arrows trace the moving letters.");
+        charts::chart(ui, "ASL chart", charts::HAND_SIZE, draw);
+        ui.collapsing("Descriptions", |ui| {
+            egui::Grid::new("ASL").striped(true).show(ui, |ui| {
+                for (letter, description) in LETTERS.chars().zip(charts::ASL) {
+                    ui.label(RichText::new(letter).strong());
+                    ui.label(description);
+                    ui.end_row();
+                }
+            });
+        });
+    }
+
+    fn ui_bacon(&mut self, ui: &mut egui::Ui) {
+        self.ui_with_wildcard(ui, "Bacon", &[4], &[]);
+        ui.separator();
+        self.grid.ui_bacon(ui, &mut self.input);
+    }
+
+    fn ui_binary(&mut self, ui: &mut egui::Ui) {
+        self.ui_with_wildcard(ui, "Binary", &[4], &[]);
+    }
+
+    fn ui_braille(&mut self, ui: &mut egui::Ui) {
+        self.ui_with_wildcard(ui, "Braille", &[1, 3, 5], &[]);
+        ui.checkbox(&mut self.use_numbers, "Use numbers");
+    }
+
+    fn ui_dancing_men(&mut self, ui: &mut egui::Ui) {
+        let stroke = egui::Stroke::new(2.0, ui.visuals().text_color());
+        let draw = |painter: &egui::Painter, rect, letter, flag| {
+            charts::draw_dancer(painter, rect, letter, flag, stroke);
+        };
+        ui.text_edit_singleline(&mut self.input);
+        charts::encode(ui, &self.input, charts::DANCER_SIZE, draw);
+        ui.separator();
+        charts::chart(
+            ui,
+            "Dancing Men chart",
+            charts::DANCER_SIZE,
+            |painter, rect, letter| {
+                draw(painter, rect, letter, false);
+            },
+        );
+        ui.label("A figure holding a flag marks the last letter of a word:");
+        charts::chart(
+            ui,
+            "Dancing Men flags",
+            charts::DANCER_SIZE,
+            |painter, rect, letter| {
+                draw(painter, rect, letter, true);
+            },
+        );
+        ui.label("Puzzles may pose the figures from Conan Doyle's story a little differently:");
+        ui.hyperlink_to(
+            "The Adventure of the Dancing Men",
+            "https://en.wikipedia.org/wiki/The_Adventure_of_the_Dancing_Men",
+        );
+    }
+
+    fn ui_flags(&mut self, ui: &mut egui::Ui) {
+        self.ui_with_wildcard(ui, "Flags", &[4], &[]);
+        ui.separator();
+        ui.text_edit_singleline(&mut self.input);
+        charts::encode(
+            ui,
+            &self.input,
+            charts::FLAG_SIZE,
+            |painter, rect, letter, _| {
+                charts::draw_flag(painter, rect, letter);
+            },
+        );
+        ui.separator();
+        charts::chart(ui, "Flags chart", charts::FLAG_SIZE, charts::draw_flag);
+    }
+
+    fn ui_morse(&mut self, ui: &mut egui::Ui) {
+        self.ui_with_wildcard(ui, "Morse", &[4], &[]);
+        ui.checkbox(&mut self.use_numbers, "Use numbers");
+        ui.separator();
+        self.morse.ui(ui);
+    }
+
+    fn ui_nato(&mut self, ui: &mut egui::Ui) {
+        ui.text_edit_singleline(&mut self.input);
+        let words = self
+            .input
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .filter(|word| !word.is_empty())
+            .collect::<Vec<_>>();
+        if !words.is_empty() && words.iter().all(|word| charts::nato_letter(word).is_some()) {
+            let decoded = words
+                .iter()
+                .filter_map(|word| charts::nato_letter(word))
+                .collect::<String>();
+            result_label(ui, decoded.as_str(), &decoded);
+        } else {
+            let encoded = charts::nato_encode(&self.input);
+            result_label(ui, encoded.as_str(), &encoded);
+        }
+
+        ui.separator();
+        egui::Grid::new("NATO").striped(true).show(ui, |ui| {
+            let symbols = LETTERS.chars().filter(|&c| c != '#');
+            for (i, (symbol, word)) in symbols.zip(charts::NATO).enumerate() {
+                ui.label(RichText::new(symbol).strong());
+                ui.label(word);
+                if i % 4 == 3 {
+                    ui.end_row();
+                }
+            }
+        });
+    }
+
+    fn ui_pigpen(&mut self, ui: &mut egui::Ui) {
+        self.ui_with_wildcard(ui, "Pigpen", &[0, 3, 4, 5], &[]);
+        ui.label("The last button is for the X-shaped letters. Mark the side the V points to.");
+        ui.separator();
+        let stroke = ui.visuals().widgets.noninteractive.fg_stroke;
+        let draw = |painter: &egui::Painter, rect, letter| {
+            charts::draw_pigpen(painter, rect, letter, egui::Stroke::new(2.0, stroke.color));
+        };
+        ui.text_edit_singleline(&mut self.input);
+        charts::encode(
+            ui,
+            &self.input,
+            charts::GLYPH_SIZE,
+            |painter, rect, letter, _| {
+                draw(painter, rect, letter);
+            },
+        );
+        ui.separator();
+        charts::chart(ui, "Pigpen chart", charts::GLYPH_SIZE, draw);
+    }
+
+    fn ui_resistor(&mut self, ui: &mut egui::Ui) {
+        ui.label("Color names decode to digits. Digits and letters (as A1Z26) encode to colors.");
+        ui.text_edit_singleline(&mut self.input);
+        let digits = self
+            .input
+            .split(|c: char| !c.is_alphabetic())
+            .filter(|word| !word.is_empty())
+            .map(charts::resistor_digit)
+            .collect::<Option<Vec<_>>>();
+        match digits {
+            Some(digits) if !digits.is_empty() => {
+                let text = digits.iter().fold(String::new(), |mut s, digit| {
+                    write!(&mut s, "{digit}").unwrap();
+                    s
+                });
+                result_label(ui, text.as_str(), &text);
+            }
+            _ => Self::ui_resistor_bands(ui, &self.input),
+        }
+
+        ui.separator();
+        ui.horizontal_wrapped(|ui| {
+            for (digit, (name, color)) in charts::RESISTOR_COLORS.iter().enumerate() {
+                ui.label(
+                    RichText::new(format!(" {digit} {name} "))
+                        .background_color(*color)
+                        .color(if color.intensity() < 0.5 {
+                            Color32::WHITE
+                        } else {
+                            Color32::BLACK
+                        }),
+                );
+            }
+        });
+    }
+
+    /// Draws each number (or letter, as A1Z26) as a group of color bands.
+    fn ui_resistor_bands(ui: &mut egui::Ui, input: &str) {
+        for word in input.split_whitespace() {
+            let digits = if let Ok(num) = word.parse::<u64>() {
+                num.to_string()
+            } else {
+                word.chars()
+                    .filter(char::is_ascii_alphabetic)
+                    .map(|c| (c.to_ascii_uppercase() as u8 - b'A' + 1).to_string())
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            };
+            ui.horizontal_wrapped(|ui| {
+                ui.label(word);
+                for digit in digits.chars() {
+                    let Some(digit) = digit.to_digit(10) else {
+                        ui.add_space(LARGE_BUTTON_SIZE / 4.0);
+                        continue;
+                    };
+                    let (name, color) = charts::RESISTOR_COLORS[digit as usize];
+                    let (rect, response) = ui.allocate_exact_size(
+                        vec2(LARGE_BUTTON_SIZE / 3.0, LARGE_BUTTON_SIZE),
+                        egui::Sense::hover(),
+                    );
+                    ui.painter().rect_filled(rect, 2.0, color);
+                    response.on_hover_text(format!("{digit} {name}"));
+                }
+            });
+        }
+    }
+
+    fn ui_semaphore(&mut self, ui: &mut egui::Ui) {
+        self.ui_with_wildcard(ui, "Semaphore", &[2, 4, 7], &[(3, '☃')]);
+        ui.checkbox(&mut self.use_numbers, "Use numbers");
+    }
+
+    fn ui_ternary(&mut self, ui: &mut egui::Ui) {
+        self.ui_with_wildcard(ui, "Ternary", &[2], &[]);
+    }
+}
+
+#[derive(PartialEq)]
+enum Tool {
+    Links,
+    Indexing,
+    Cipher,
+    Transposition,
+    Historical,
+    Lookup,
+    Sets,
+    Rebus,
+}
+
+pub struct PuzzleHuntTools {
+    tab: Tool,
+
+    indexing: Indexing,
+    cipher: Cipher,
+    transposition: transposition::Transposition,
+    historical: historical::HistoricalCiphers,
+    lookup: lookup::Lookup,
+    rebus: rebus::Rebus,
+    sets: sets::Sets,
+}
+
+impl PuzzleHuntTools {
+    fn ui_indexing(&mut self, ui: &mut egui::Ui) {
+        let (input_length, extracted) = self.indexing.get();
+
+        ui.label(format!("Input ({input_length} chars)"));
+        ui.add(egui::TextEdit::singleline(&mut self.indexing.input));
+        ui.label("Indices");
+        ui.add(egui::TextEdit::singleline(&mut self.indexing.indices));
+        ui.checkbox(&mut self.indexing.use_0_indexing, "Use 0-indexing");
+        ui.add_space(6.0);
+
+        ui.heading("Result");
+        ui.horizontal(|ui| {
+            result_label(ui, extracted.as_str(), &extracted);
+            send_button(ui, &extracted);
+        });
+    }
+
+    fn ui_cipher(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal_wrapped(|ui| {
+            for cipher_mode in CipherMode::iter() {
+                let size = ui.text_style_height(&egui::TextStyle::Button);
+                let name = color_emoji::atoms(ui.ctx(), cipher_mode.name(), size);
+                let response = ui.selectable_value(&mut self.cipher.mode, cipher_mode, name);
+                if response.clicked() {
+                    self.cipher.criteria = [CipherCriterion::Blank; 10];
+                    self.cipher.use_numbers = false;
+                }
+            }
+        });
+        ui.separator();
+
+        match &self.cipher.mode {
+            CipherMode::A1Z26 => self.cipher.ui_a1z26(ui),
+            CipherMode::Asl => self.cipher.ui_asl(ui),
+            CipherMode::Bacon => self.cipher.ui_bacon(ui),
+            CipherMode::Bifid => self.cipher.grid.ui_bifid(ui, &mut self.cipher.input),
+            CipherMode::Binary => self.cipher.ui_binary(ui),
+            CipherMode::Braille => self.cipher.ui_braille(ui),
+            CipherMode::DancingMen => self.cipher.ui_dancing_men(ui),
+            CipherMode::Flags => self.cipher.ui_flags(ui),
+            CipherMode::Keyboard => self.cipher.devices.ui_keyboard(ui, &mut self.cipher.input),
+            CipherMode::Keypad => devices::Devices::ui_keypad(ui, &mut self.cipher.input),
+            CipherMode::Morse => self.cipher.ui_morse(ui),
+            CipherMode::Nato => self.cipher.ui_nato(ui),
+            CipherMode::Pigpen => self.cipher.ui_pigpen(ui),
+            CipherMode::Polybius => self.cipher.grid.ui_polybius(ui, &mut self.cipher.input),
+            CipherMode::Resistor => self.cipher.ui_resistor(ui),
+            CipherMode::Semaphore => self.cipher.ui_semaphore(ui),
+            CipherMode::TapCode => {
+                grid_ciphers::GridCiphers::ui_tap_code(ui, &mut self.cipher.input);
+            }
+            CipherMode::Ternary => self.cipher.ui_ternary(ui),
+        };
+    }
+}
+
+impl HouseGadget for PuzzleHuntTools {
+    fn new() -> Self {
+        Self {
+            tab: Tool::Links,
+            indexing: Default::default(),
+            cipher: Cipher {
+                mode: CipherMode::A1Z26,
+                use_numbers: false,
+                criteria: [CipherCriterion::Blank; 10],
+                input: String::default(),
+                morse: Default::default(),
+                grid: Default::default(),
+                devices: Default::default(),
+            },
+            transposition: Default::default(),
+            historical: Default::default(),
+            lookup: Default::default(),
+            rebus: Default::default(),
+            sets: Default::default(),
+        }
+    }
+
+    fn title(&self) -> String {
+        "Puzzlehunt Tools".to_owned()
+    }
+
+    fn input(&mut self) -> Option<&mut String> {
+        match self.tab {
+            // The links have no input, so text sent there goes to the rebus or cipher tab.
+            Tool::Links | Tool::Cipher => Some(&mut self.cipher.input),
+            Tool::Indexing => Some(&mut self.indexing.input),
+            Tool::Transposition => Some(&mut self.transposition.input),
+            Tool::Historical => Some(&mut self.historical.input),
+            Tool::Lookup => Some(&mut self.lookup.input),
+            Tool::Sets => Some(&mut self.sets.input),
+            Tool::Rebus => Some(&mut self.rebus.input),
+        }
+    }
+
+    fn receive(&mut self, id: egui::Id, message: &Message) {
+        if let Message::SetInput { to, text } = message {
+            if *to != id {
+                return;
+            }
+            if self.tab == Tool::Links {
+                self.tab = if rebus::is_rebus(text) {
+                    Tool::Rebus
+                } else {
+                    Tool::Cipher
+                };
+            }
+            if let Some(input) = self.input() {
+                text.clone_into(input);
+            }
+        }
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut self.tab, Tool::Links, "Useful links");
+            ui.selectable_value(&mut self.tab, Tool::Indexing, "Indexing");
+            ui.selectable_value(&mut self.tab, Tool::Cipher, "Cipher");
+            ui.selectable_value(&mut self.tab, Tool::Transposition, "Transposition");
+            ui.selectable_value(&mut self.tab, Tool::Historical, "Historical");
+            ui.selectable_value(&mut self.tab, Tool::Lookup, "Lookup");
+            ui.selectable_value(&mut self.tab, Tool::Sets, "Sets");
+            ui.selectable_value(&mut self.tab, Tool::Rebus, "Rebus");
+        });
+        ui.separator();
+
+        match self.tab {
+            Tool::Links => useful_links(ui),
+            Tool::Indexing => self.ui_indexing(ui),
+            Tool::Cipher => self.ui_cipher(ui),
+            Tool::Transposition => self.transposition.ui(ui),
+            Tool::Historical => self.historical.ui(ui),
+            Tool::Lookup => self.lookup.ui(ui),
+            Tool::Sets => self.sets.ui(ui),
+            Tool::Rebus => {
+                if let Some(names) = self.rebus.ui(ui) {
+                    self.indexing.input = names;
+                    self.tab = Tool::Indexing;
+                }
+            }
+        };
+    }
+}
+
+/// A monospace result that is copied when clicked.
+fn copyable(ui: &mut egui::Ui, text: &str) {
+    result_label(ui, RichText::new(text).monospace(), text);
+}
+
+fn useful_links(ui: &mut egui::Ui) {
+    ui.heading("Lists");
+    ui.hyperlink_to(
+        "MIT Hunt DB",
+        "http://devjoe.appspot.com/huntindex/index/index.html",
+    );
+    ui.hyperlink_to(
+        "Sets of Things",
+        "https://phenomist.wordpress.com/storage/sets/",
+    );
+
+    ui.heading("Phrase finder");
+    ui.hyperlink_to("Nutrimatic", "https://nutrimatic.org");
+    ui.hyperlink_to("Qat (word finder)", "https://quinapalus.com/cgi-bin/match");
+
+    ui.heading("Solver");
+    ui.hyperlink_to("Noq (logic puzzle)", "https://www.noq.solutions");
+    ui.hyperlink_to(
+        "Wordplays (crossword)",
+        "https://www.wordplays.com/crossword-solver/",
+    );
+    ui.hyperlink_to("qhex (multi tools)", "https://tools.qhex.org/");
+    ui.hyperlink_to("quipquip (cryptogram)", "https://quipqiup.com/");
+}
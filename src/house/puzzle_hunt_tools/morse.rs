@@ -0,0 +1,367 @@
+//! Morse playback as sound or a blinking light, and a tap key that decodes as you key.
+
+use egui::{Color32, Key, RichText, Sense, vec2};
+
+use super::super::color_emoji;
+use super::super::scratchpad::result_label;
+use super::audio::{AudioOutput, tone_samples};
+use super::morse_recording::RecordingDecoder;
+use super::{LETTERS, MORSE};
+
+const TONE_FREQUENCY: f32 = 600.0;
+const LIGHT_RADIUS: f32 = 24.0;
+const KEY_SIZE: f32 = 120.0;
+const MIN_WPM: f32 = 5.0;
+const MAX_WPM: f32 = 40.0;
+
+/// Turns a `MORSE` entry such as `"12000"` into `".-"`.
+fn pattern(entry: &str) -> String {
+    entry
+        .chars()
+        .filter_map(|c| match c {
+            '1' => Some('.'),
+            '2' => Some('-'),
+            _ => None,
+        })
+        .collect()
+}
+
+pub fn letter_of(code: &str) -> Option<char> {
+    if code.is_empty() {
+        return None;
+    }
+    MORSE
+        .iter()
+        .position(|entry| pattern(entry) == code)
+        .map(|i| LETTERS.as_bytes()[i] as char)
+}
+
+/// Normalizes the dots and dashes people paste from elsewhere.
+/// Returns `None` if the input doesn't look like Morse code.
+pub fn normalize(input: &str) -> Option<String> {
+    let code = input
+        .chars()
+        .map(|c| match c {
+            '·' | '•' | '∙' => Some('.'),
+            '—' | '–' | '−' | '_' => Some('-'),
+            '.' | '-' | '/' | '|' => Some(c),
+            c if c.is_whitespace() => Some(' '),
+            _ => None,
+        })
+        .collect::<Option<String>>()?;
+    code.contains(['.', '-']).then_some(code)
+}
+
+/// Decodes normalized code, with letters separated by spaces and words by `/`.
+pub fn decode(code: &str) -> String {
+    code.split(['/', '|'])
+        .map(|word| {
+            word.split_whitespace()
+                .map(|letter| letter_of(letter).unwrap_or('✖'))
+                .collect::<String>()
+        })
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Encodes letters and digits; other characters are dropped.
+pub fn encode(text: &str) -> String {
+    text.split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter_map(|c| {
+                    let index = LETTERS.find(c.to_ascii_uppercase()).filter(|_| c != '#')?;
+                    Some(pattern(MORSE[index]))
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" / ")
+}
+
+#[derive(Clone, Copy)]
+struct Timing {
+    wpm: f32,
+    farnsworth_wpm: f32,
+}
+
+impl Timing {
+    /// Length of a dot in seconds, using PARIS as the standard word.
+    fn dot(&self) -> f32 {
+        1.2 / self.wpm
+    }
+
+    /// Gaps between letters and between words in seconds.
+    /// Farnsworth spacing keeps the letters fast but stretches these gaps.
+    fn gaps(&self) -> (f32, f32) {
+        let (c, s) = (self.wpm, self.farnsworth_wpm.min(self.wpm));
+        let delay = (60.0 * c - 37.2 * s) / (s * c);
+        (3.0 * delay / 19.0, 7.0 * delay / 19.0)
+    }
+
+    /// Converts normalized code into on/off segments.
+    fn segments(&self, code: &str) -> Vec<(bool, f32)> {
+        let dot = self.dot();
+        let (letter_gap, word_gap) = self.gaps();
+        let mut segments = Vec::new();
+        for (w, word) in code
+            .split(['/', '|'])
+            .filter(|w| !w.trim().is_empty())
+            .enumerate()
+        {
+            if w > 0 {
+                segments.push((false, word_gap));
+            }
+            for (l, letter) in word.split_whitespace().enumerate() {
+                if l > 0 {
+                    segments.push((false, letter_gap));
+                }
+                for (s, symbol) in letter.chars().enumerate() {
+                    if s > 0 {
+                        segments.push((false, dot));
+                    }
+                    segments.push((true, if symbol == '-' { 3.0 * dot } else { dot }));
+                }
+            }
+        }
+        segments
+    }
+}
+
+struct Playback {
+    segments: Vec<(bool, f32)>,
+    started: f64,
+    blink: bool,
+}
+
+impl Playback {
+    /// Whether the signal is on at `now`, or `None` once playback is over.
+    fn is_on(&self, now: f64) -> Option<bool> {
+        let mut elapsed = (now - self.started) as f32;
+        for &(on, seconds) in &self.segments {
+            if elapsed < seconds {
+                return Some(on);
+            }
+            elapsed -= seconds;
+        }
+        None
+    }
+}
+
+#[derive(Default)]
+struct Keyer {
+    is_down: bool,
+    pressed_at: f64,
+    released_at: Option<f64>,
+    symbols: String,
+    decoded: String,
+}
+
+impl Keyer {
+    fn update(&mut self, down: bool, now: f64, timing: Timing) {
+        let dot = f64::from(timing.dot());
+        match (self.is_down, down) {
+            (false, true) => self.pressed_at = now,
+            (true, false) => {
+                let held = now - self.pressed_at;
+                self.symbols.push(if held < 2.0 * dot { '.' } else { '-' });
+                self.released_at = Some(now);
+            }
+            _ => {}
+        }
+        self.is_down = down;
+
+        let Some(released_at) = self.released_at.filter(|_| !down) else {
+            return;
+        };
+        let (letter_gap, word_gap) = timing.gaps();
+        let gap = now - released_at;
+        if !self.symbols.is_empty() && gap > f64::from(timing.dot() + letter_gap) / 2.0 {
+            self.decoded.push(letter_of(&self.symbols).unwrap_or('✖'));
+            self.symbols.clear();
+        }
+        if gap > f64::from(letter_gap + word_gap) / 2.0 {
+            self.decoded.push(' ');
+            self.released_at = None;
+        }
+    }
+}
+
+pub struct MorseStation {
+    input: String,
+    timing: Timing,
+    audio: Option<AudioOutput>,
+    audio_unavailable: bool,
+    playback: Option<Playback>,
+    keyer: Keyer,
+    recording: RecordingDecoder,
+}
+
+impl Default for MorseStation {
+    fn default() -> Self {
+        Self {
+            input: String::new(),
+            timing: Timing {
+                wpm: 20.0,
+                farnsworth_wpm: 20.0,
+            },
+            audio: None,
+            audio_unavailable: false,
+            playback: None,
+            keyer: Keyer::default(),
+            recording: RecordingDecoder::default(),
+        }
+    }
+}
+
+impl MorseStation {
+    pub fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.heading("Playback");
+        ui.label("Text or code (letters separated by spaces, words by /)");
+        ui.text_edit_singleline(&mut self.input);
+        let code = if let Some(code) = normalize(&self.input) {
+            let decoded = decode(&code);
+            result_label(ui, decoded.as_str(), &decoded);
+            code
+        } else {
+            let code = encode(&self.input);
+            ui.label(&code);
+            code
+        };
+
+        ui.add(egui::Slider::new(&mut self.timing.wpm, MIN_WPM..=MAX_WPM).text("WPM"));
+        ui.add(
+            egui::Slider::new(&mut self.timing.farnsworth_wpm, MIN_WPM..=self.timing.wpm)
+                .text("Farnsworth WPM"),
+        );
+        self.timing.farnsworth_wpm = self.timing.farnsworth_wpm.min(self.timing.wpm);
+
+        ui.horizontal(|ui| {
+            if ui
+                .button(color_emoji::button(ui, "🔊 Play sound"))
+                .clicked()
+            {
+                self.play(ui, &code, false);
+            }
+            if ui.button(color_emoji::button(ui, "💡 Blink")).clicked() {
+                self.play(ui, &code, true);
+            }
+            if ui.button("⏹ Stop").clicked() {
+                self.stop();
+            }
+        });
+        if self.audio_unavailable {
+            ui.label(
+                RichText::new("⚠ Audio is unavailable in this build")
+                    .color(ui.visuals().warn_fg_color),
+            );
+        }
+        self.ui_light(ui);
+
+        ui.separator();
+        self.ui_keyer(ui);
+
+        ui.separator();
+        self.recording.ui(ui);
+    }
+
+    fn play(&mut self, ui: &egui::Ui, code: &str, blink: bool) {
+        self.stop();
+        let segments = self.timing.segments(code);
+        if !blink {
+            if self.audio.is_none() {
+                self.audio = AudioOutput::new();
+            }
+            self.audio_unavailable = self.audio.is_none();
+            let Some(audio) = &mut self.audio else {
+                return;
+            };
+            audio.play(&tone_samples(&segments, TONE_FREQUENCY));
+        }
+        self.playback = Some(Playback {
+            segments,
+            started: ui.input(|i| i.time),
+            blink,
+        });
+    }
+
+    fn stop(&mut self) {
+        if let Some(audio) = &mut self.audio {
+            audio.stop();
+        }
+        self.playback = None;
+    }
+
+    fn ui_light(&mut self, ui: &mut egui::Ui) {
+        let now = ui.input(|i| i.time);
+        let state = self.playback.as_ref().map(|p| (p.blink, p.is_on(now)));
+        let lit = match state {
+            Some((_, None)) => {
+                self.playback = None;
+                false
+            }
+            Some((blink, Some(on))) => {
+                ui.ctx().request_repaint();
+                blink && on
+            }
+            None => false,
+        };
+
+        let (rect, _) =
+            ui.allocate_exact_size(vec2(LIGHT_RADIUS, LIGHT_RADIUS) * 2.0, Sense::hover());
+        let color = if lit {
+            Color32::from_rgb(255, 210, 0)
+        } else {
+            Color32::from_gray(60)
+        };
+        ui.painter()
+            .circle_filled(rect.center(), LIGHT_RADIUS, color);
+    }
+
+    fn ui_keyer(&mut self, ui: &mut egui::Ui) {
+        ui.heading("Keyer");
+        ui.label("Hold the key with the mouse, or click it and hold the space bar.");
+
+        let (rect, response) =
+            ui.allocate_exact_size(vec2(KEY_SIZE, KEY_SIZE / 2.0), Sense::click_and_drag());
+        if response.clicked() {
+            response.request_focus();
+        }
+        let down = response.is_pointer_button_down_on()
+            || (response.has_focus() && ui.input(|i| i.key_down(Key::Space)));
+        let now = ui.input(|i| i.time);
+        self.keyer.update(down, now, self.timing);
+        if down || self.keyer.released_at.is_some() {
+            ui.ctx().request_repaint();
+        }
+
+        let visuals = ui.style().interact_selectable(&response, down);
+        ui.painter()
+            .rect_filled(rect, visuals.corner_radius, visuals.bg_fill);
+        ui.painter().text(
+            rect.center(),
+            egui::Align2::CENTER_CENTER,
+            if response.has_focus() {
+                "⌨ KEY"
+            } else {
+                "KEY"
+            },
+            egui::FontId::proportional(20.0),
+            visuals.text_color(),
+        );
+
+        ui.label(RichText::new(&self.keyer.symbols).monospace());
+        result_label(
+            ui,
+            RichText::new(&self.keyer.decoded).size(20.0),
+            &self.keyer.decoded,
+        );
+        if ui.button("Clear").clicked() {
+            self.keyer.symbols.clear();
+            self.keyer.decoded.clear();
+        }
+    }
+}
@@ -0,0 +1,335 @@
+//! Decodes Morse from a WAV or OGG recording by following the loudness of the tone.
+
+use std::io::Cursor;
+
+use egui::{Color32, RichText, Sense, Stroke, pos2, vec2};
+
+use super::super::scratchpad::result_label;
+use super::morse::letter_of;
+
+const WINDOW_SECONDS: f32 = 0.005;
+const SMOOTHING_WINDOWS: usize = 2;
+const WAVEFORM_HEIGHT: f32 = 80.0;
+const LETTER_ROW_HEIGHT: f32 = 16.0;
+
+const FILE_FORMAT_ERR: &str = "Only WAV and OGG files are supported";
+const NO_TONE_ERR: &str = "Couldn't find any tone in the recording";
+
+/// Mono samples in `-1.0..=1.0`.
+struct Recording {
+    samples: Vec<f32>,
+    sample_rate: u32,
+}
+
+fn downmix(interleaved: &[f32], channels: usize) -> Vec<f32> {
+    interleaved
+        .chunks(channels.max(1))
+        .map(|frame| frame.iter().sum::<f32>() / frame.len() as f32)
+        .collect()
+}
+
+fn read_wav(bytes: &[u8]) -> Result<Recording, String> {
+    let mut reader = hound::WavReader::new(Cursor::new(bytes)).map_err(|e| e.to_string())?;
+    let spec = reader.spec();
+    let interleaved = match spec.sample_format {
+        hound::SampleFormat::Float => reader
+            .samples::<f32>()
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| e.to_string())?,
+        hound::SampleFormat::Int => {
+            let scale = (1_i64 << (spec.bits_per_sample - 1)) as f32;
+            reader
+                .samples::<i32>()
+                .map(|sample| sample.map(|s| s as f32 / scale))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| e.to_string())?
+        }
+    };
+    Ok(Recording {
+        samples: downmix(&interleaved, usize::from(spec.channels)),
+        sample_rate: spec.sample_rate,
+    })
+}
+
+fn read_ogg(bytes: &[u8]) -> Result<Recording, String> {
+    let mut reader =
+        lewton::inside_ogg::OggStreamReader::new(Cursor::new(bytes)).map_err(|e| e.to_string())?;
+    let channels = usize::from(reader.ident_hdr.audio_channels);
+    let mut interleaved = Vec::new();
+    while let Some(packet) = reader.read_dec_packet_itl().map_err(|e| e.to_string())? {
+        interleaved.extend(packet.into_iter().map(|s| f32::from(s) / 32768.0));
+    }
+    Ok(Recording {
+        samples: downmix(&interleaved, channels),
+        sample_rate: reader.ident_hdr.audio_sample_rate,
+    })
+}
+
+/// A tone (or silence) spanning `len` envelope windows.
+struct Run {
+    on: bool,
+    start: usize,
+    len: usize,
+}
+
+struct Analysis {
+    envelope: Vec<f32>,
+    peak: f32,
+    threshold: f32,
+    runs: Vec<Run>,
+    /// Estimated dot length in envelope windows.
+    dot: f32,
+    /// Each decoded letter and the window it starts at.
+    letters: Vec<(usize, char)>,
+    code: String,
+    text: String,
+}
+
+fn envelope(recording: &Recording) -> Vec<f32> {
+    let window = ((recording.sample_rate as f32 * WINDOW_SECONDS) as usize).max(1);
+    recording
+        .samples
+        .chunks(window)
+        .map(|chunk| (chunk.iter().map(|s| s * s).sum::<f32>() / chunk.len() as f32).sqrt())
+        .collect()
+}
+
+fn percentile(sorted: &[f32], p: f32) -> f32 {
+    sorted[((sorted.len() - 1) as f32 * p) as usize]
+}
+
+/// Splits the envelope into tone and silence, ignoring blips shorter than the smoothing window.
+fn runs(envelope: &[f32], threshold: f32) -> Vec<Run> {
+    let on = (0..envelope.len())
+        .map(|i| {
+            let lo = i.saturating_sub(SMOOTHING_WINDOWS);
+            let hi = (i + SMOOTHING_WINDOWS + 1).min(envelope.len());
+            let loud = envelope[lo..hi].iter().filter(|&&e| e > threshold).count();
+            2 * loud > hi - lo
+        })
+        .collect::<Vec<_>>();
+
+    let mut runs: Vec<Run> = Vec::new();
+    for (i, &state) in on.iter().enumerate() {
+        match runs.last_mut() {
+            Some(run) if run.on == state => run.len += 1,
+            _ => runs.push(Run {
+                on: state,
+                start: i,
+                len: 1,
+            }),
+        }
+    }
+    runs
+}
+
+/// Estimates the dot length by splitting the tone lengths into two clusters.
+/// If all tones are about the same length, they're assumed to be dots.
+fn estimate_dot(tones: &[usize]) -> f32 {
+    let lengths = tones.iter().map(|&len| len as f32).collect::<Vec<_>>();
+    let mut short = lengths.iter().copied().fold(f32::INFINITY, f32::min);
+    let mut long = lengths.iter().copied().fold(0.0, f32::max);
+    if long < 2.0 * short {
+        return lengths.iter().sum::<f32>() / lengths.len() as f32;
+    }
+    for _ in 0..16 {
+        let (shorts, longs): (Vec<f32>, Vec<f32>) =
+            lengths.iter().partition(|&&len| len - short < long - len);
+        short = shorts.iter().sum::<f32>() / shorts.len().max(1) as f32;
+        long = longs.iter().sum::<f32>() / longs.len().max(1) as f32;
+    }
+    (short + long / 3.0) / 2.0
+}
+
+fn flush_letter(letters: &mut Vec<(usize, char)>, symbols: &mut String, start: usize) {
+    if !symbols.is_empty() {
+        letters.push((start, letter_of(symbols).unwrap_or('✖')));
+        symbols.clear();
+    }
+}
+
+fn analyze(recording: &Recording) -> Result<Analysis, String> {
+    let envelope = envelope(recording);
+    let mut sorted = envelope.clone();
+    sorted.sort_by(f32::total_cmp);
+    if sorted.is_empty() {
+        return Err(NO_TONE_ERR.to_owned());
+    }
+    let (noise, peak) = (percentile(&sorted, 0.2), percentile(&sorted, 0.98));
+    if peak <= noise * 1.5 {
+        return Err(NO_TONE_ERR.to_owned());
+    }
+    let threshold = noise + (peak - noise) / 2.0;
+
+    let runs = runs(&envelope, threshold);
+    let tones = runs
+        .iter()
+        .filter(|r| r.on)
+        .map(|r| r.len)
+        .collect::<Vec<_>>();
+    if tones.is_empty() {
+        return Err(NO_TONE_ERR.to_owned());
+    }
+    let dot = estimate_dot(&tones);
+
+    let mut code = String::new();
+    let mut letters = Vec::new();
+    let mut symbols = String::new();
+    let mut letter_start = 0;
+    for run in runs.iter().skip_while(|r| !r.on) {
+        let len = run.len as f32;
+        if run.on {
+            if symbols.is_empty() {
+                letter_start = run.start;
+            }
+            let symbol = if len < 2.0 * dot { '.' } else { '-' };
+            symbols.push(symbol);
+            code.push(symbol);
+        } else if len >= 2.0 * dot {
+            flush_letter(&mut letters, &mut symbols, letter_start);
+            if len >= 5.0 * dot {
+                letters.push((run.start, ' '));
+                code.push_str(" / ");
+            } else {
+                code.push(' ');
+            }
+        }
+    }
+    flush_letter(&mut letters, &mut symbols, letter_start);
+    let text = letters
+        .iter()
+        .map(|&(_, letter)| letter)
+        .collect::<String>();
+
+    Ok(Analysis {
+        envelope,
+        peak,
+        threshold,
+        runs,
+        dot,
+        letters,
+        code: code.trim_end_matches([' ', '/']).to_owned(),
+        text: text.trim_end().to_owned(),
+    })
+}
+
+#[derive(Default)]
+pub struct RecordingDecoder {
+    file_name: String,
+    result: Option<Result<Analysis, String>>,
+    zoom: f32,
+}
+
+impl RecordingDecoder {
+    fn load(&mut self, file: &egui::DroppedFile) {
+        let bytes = match (&file.bytes, &file.path) {
+            (Some(bytes), _) => Ok(bytes.to_vec()),
+            (None, Some(path)) => std::fs::read(path).map_err(|e| e.to_string()),
+            (None, None) => Err(FILE_FORMAT_ERR.to_owned()),
+        };
+        let recording = bytes.and_then(|bytes| match bytes.get(..4) {
+            Some(b"RIFF") => read_wav(&bytes),
+            Some(b"OggS") => read_ogg(&bytes),
+            _ => Err(FILE_FORMAT_ERR.to_owned()),
+        });
+
+        self.file_name = match &file.path {
+            Some(path) => path.display().to_string(),
+            None => file.name.clone(),
+        };
+        self.result = Some(recording.and_then(|recording| analyze(&recording)));
+        self.zoom = 1.0;
+    }
+
+    pub fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.heading("Recording");
+        let drop_area = ui
+            .group(|ui| {
+                ui.set_width(ui.available_width());
+                ui.label("Drag and drop a WAV or OGG file here.");
+            })
+            .response
+            .rect;
+        // Every open station sees the same drop, so only the one under the pointer takes it.
+        if ui.rect_contains_pointer(drop_area) {
+            let (hovering, dropped) =
+                ui.input(|i| (!i.raw.hovered_files.is_empty(), i.raw.dropped_files.clone()));
+            if let Some(file) = dropped.first() {
+                self.load(file);
+            }
+            if hovering {
+                let stroke = ui.visuals().selection.stroke;
+                ui.painter()
+                    .rect_stroke(drop_area, 2.0, stroke, egui::StrokeKind::Inside);
+            }
+        }
+
+        match &self.result {
+            None => {}
+            Some(Err(err)) => {
+                ui.label(RichText::new(format!("⚠ {err}")).color(ui.visuals().warn_fg_color));
+            }
+            Some(Ok(analysis)) => {
+                ui.label(&self.file_name);
+                ui.label(format!(
+                    "Dot length: {:.0} ms",
+                    analysis.dot * WINDOW_SECONDS * 1000.0
+                ));
+                ui.label(RichText::new(&analysis.code).monospace());
+                result_label(ui, RichText::new(&analysis.text).size(20.0), &analysis.text);
+                ui.add(egui::Slider::new(&mut self.zoom, 0.25..=4.0).text("Zoom"));
+                waveform(ui, analysis, self.zoom);
+            }
+        }
+    }
+}
+
+/// Draws the envelope with the detected dits (blue) and dahs (orange) underneath.
+fn waveform(ui: &mut egui::Ui, analysis: &Analysis, zoom: f32) {
+    let width = analysis.envelope.len() as f32 * zoom;
+    let height = WAVEFORM_HEIGHT + 2.0 * LETTER_ROW_HEIGHT;
+    egui::ScrollArea::horizontal().show_viewport(ui, |ui, viewport| {
+        let (rect, _) = ui.allocate_exact_size(vec2(width, height), Sense::hover());
+        let painter = ui.painter_at(rect);
+        let x = |window: usize| rect.left() + window as f32 * zoom;
+        let first = (viewport.left() / zoom).max(0.0) as usize;
+        let last = ((viewport.right() / zoom) as usize + 1).min(analysis.envelope.len());
+        let base = rect.top() + WAVEFORM_HEIGHT;
+
+        painter.rect_filled(rect, 0.0, ui.visuals().extreme_bg_color);
+        let stroke = Stroke::new(1.0, ui.visuals().text_color());
+        for window in first..last {
+            let level = analysis.envelope[window] / analysis.peak;
+            let top = base - level.min(1.0) * WAVEFORM_HEIGHT;
+            painter.line_segment([pos2(x(window), base), pos2(x(window), top)], stroke);
+        }
+        let threshold = base - analysis.threshold / analysis.peak * WAVEFORM_HEIGHT;
+        painter.hline(
+            rect.x_range(),
+            threshold,
+            Stroke::new(1.0, Color32::RED.gamma_multiply(0.6)),
+        );
+
+        for run in analysis.runs.iter().filter(|run| run.on) {
+            let color = if (run.len as f32) < 2.0 * analysis.dot {
+                Color32::from_rgb(60, 120, 220)
+            } else {
+                Color32::from_rgb(240, 140, 30)
+            };
+            let mark = egui::Rect::from_min_max(
+                pos2(x(run.start), base + 2.0),
+                pos2(x(run.start + run.len), base + LETTER_ROW_HEIGHT - 2.0),
+            );
+            painter.rect_filled(mark, 2.0, color);
+        }
+        for &(start, letter) in &analysis.letters {
+            painter.text(
+                pos2(x(start), base + LETTER_ROW_HEIGHT),
+                egui::Align2::LEFT_TOP,
+                letter,
+                egui::FontId::monospace(14.0),
+                ui.visuals().strong_text_color(),
+            );
+        }
+    });
+}
@@ -0,0 +1,164 @@
+//! Emoji rebuses: what each emoji is called, and letters indexed out of those names.
+
+use egui::RichText;
+use emojis::{Emoji, SkinTone};
+
+use super::super::{color_emoji, emoji, scratchpad};
+use super::{LARGE_FONT_SIZE, copyable};
+
+/// The longest emoji sequences, like families and subdivision flags, are this many characters.
+const MAX_EMOJI_CHARS: usize = 12;
+const VARIATION_SELECTOR: char = '\u{fe0f}';
+const MAX_INDEX: usize = 99;
+
+enum Piece {
+    Emoji(&'static Emoji),
+    /// Anything that is not an emoji, like letters or `+` between emojis.
+    Other(char),
+}
+
+/// Splits the text into emojis, longest sequences first, and other characters.
+/// Skin tones are dropped, so the names are the plain ones.
+fn pieces(text: &str) -> Vec<Piece> {
+    let mut pieces = Vec::new();
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        let ends = rest
+            .char_indices()
+            .map(|(i, c)| i + c.len_utf8())
+            .take(MAX_EMOJI_CHARS)
+            .collect::<Vec<_>>();
+        let found = ends
+            .iter()
+            .rev()
+            .find_map(|&end| Some((emojis::get(&rest[..end])?, end)));
+        if let Some((emoji, end)) = found {
+            let plain = emoji.with_skin_tone(SkinTone::Default).unwrap_or(emoji);
+            pieces.push(Piece::Emoji(plain));
+            rest = &rest[end..];
+        } else {
+            if !c.is_whitespace() && c != VARIATION_SELECTOR {
+                pieces.push(Piece::Other(c));
+            }
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    pieces
+}
+
+/// Whether the text is nothing but emojis, so it is worth opening here.
+pub fn is_rebus(text: &str) -> bool {
+    let pieces = pieces(text);
+    !pieces.is_empty() && pieces.iter().all(|piece| matches!(piece, Piece::Emoji(_)))
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Source {
+    Name,
+    Shortcode,
+}
+
+impl Piece {
+    fn text(&self, source: Source) -> String {
+        match (self, source) {
+            (Self::Emoji(emoji), Source::Shortcode) => {
+                emoji.shortcode().unwrap_or(emoji.name()).to_owned()
+            }
+            (Self::Emoji(emoji), Source::Name) => emoji.name().to_owned(),
+            (Self::Other(c), _) => c.to_string(),
+        }
+    }
+}
+
+/// The `n`th letter of the text, counting from 1 and skipping anything but letters.
+fn nth_letter(text: &str, n: usize) -> Option<char> {
+    let index = n.checked_sub(1)?;
+    let letter = text.chars().filter(|c| c.is_alphabetic()).nth(index)?;
+    letter.to_uppercase().next()
+}
+
+pub struct Rebus {
+    pub(super) input: String,
+    source: Source,
+    /// Which letter to take from each piece, counting from 1.
+    indices: Vec<usize>,
+}
+
+impl Default for Rebus {
+    fn default() -> Self {
+        Self {
+            input: String::new(),
+            source: Source::Name,
+            indices: Vec::new(),
+        }
+    }
+}
+
+impl Rebus {
+    /// Returns the names to index into when they are sent to the indexing tool.
+    pub fn ui(&mut self, ui: &mut egui::Ui) -> Option<String> {
+        ui.label("Emojis");
+        ui.text_edit_singleline(&mut self.input);
+        let pieces = pieces(&self.input);
+        self.indices.resize(pieces.len(), 1);
+
+        let mut to_indexing = None;
+        ui.horizontal(|ui| {
+            ui.label("Index into");
+            ui.selectable_value(&mut self.source, Source::Name, "Names");
+            ui.selectable_value(&mut self.source, Source::Shortcode, "Shortcodes");
+            if ui.button("First letters").clicked() {
+                self.indices.fill(1);
+            }
+            if ui.button("Send to Indexing").clicked() {
+                let texts = pieces.iter().map(|piece| piece.text(self.source));
+                to_indexing = Some(texts.collect::<Vec<_>>().join(" "));
+            }
+        });
+        ui.separator();
+        if pieces.is_empty() {
+            ui.label("Paste emojis from a puzzle, or compose them in the emoji picker.");
+            return to_indexing;
+        }
+
+        let mut extracted = String::new();
+        egui::Grid::new("Rebus").striped(true).show(ui, |ui| {
+            for heading in ["", "Name", "Shortcodes", "Keywords", "Index", "Letter"] {
+                ui.strong(heading);
+            }
+            ui.end_row();
+            for (piece, index) in pieces.iter().zip(&mut self.indices) {
+                match piece {
+                    Piece::Emoji(emoji) => {
+                        let glyph = emoji.as_str();
+                        match color_emoji::image(ui.ctx(), glyph, LARGE_FONT_SIZE) {
+                            Some(image) => ui.add(image),
+                            None => ui.label(RichText::new(glyph).size(LARGE_FONT_SIZE)),
+                        };
+                        copyable(ui, emoji.name());
+                        let shortcodes = emoji.shortcodes().collect::<Vec<_>>();
+                        ui.label(shortcodes.join(" "));
+                        ui.label(emoji::keywords(emoji).join(", "));
+                    }
+                    Piece::Other(c) => {
+                        ui.label(RichText::new(c.to_string()).size(LARGE_FONT_SIZE));
+                        ui.weak("not an emoji");
+                        ui.label("");
+                        ui.label("");
+                    }
+                }
+                ui.add(egui::DragValue::new(index).range(1..=MAX_INDEX));
+                let letter = nth_letter(&piece.text(self.source), *index).unwrap_or('✖');
+                ui.label(RichText::new(letter.to_string()).strong());
+                extracted.push(letter);
+                ui.end_row();
+            }
+        });
+        ui.horizontal(|ui| {
+            ui.label("Letters:");
+            copyable(ui, &extracted);
+            scratchpad::send_button(ui, &extracted);
+        });
+        to_indexing
+    }
+}
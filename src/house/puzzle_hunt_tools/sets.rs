@@ -0,0 +1,263 @@
+//! An offline catalog of ordered sets, to find which set a list of items comes from.
+
+use std::sync::OnceLock;
+
+use egui::RichText;
+
+use super::{ALPHABET_CNT, LETTERS, charts, copyable, lookup};
+
+const MAX_MATCHES: usize = 10;
+
+/// Ordered sets, with `/` separating other ways to write an item.
+#[rustfmt::skip]
+const SETS: [(&str, &[&str]); 23] = [
+    ("Zodiac signs", &[
+        "Aries", "Taurus", "Gemini", "Cancer", "Leo", "Virgo", "Libra", "Scorpio", "Sagittarius",
+        "Capricorn", "Aquarius", "Pisces",
+    ]),
+    ("Chinese zodiac", &[
+        "Rat", "Ox", "Tiger", "Rabbit", "Dragon", "Snake", "Horse", "Goat/Sheep", "Monkey",
+        "Rooster", "Dog", "Pig",
+    ]),
+    ("Planets", &[
+        "Mercury", "Venus", "Earth", "Mars", "Jupiter", "Saturn", "Uranus", "Neptune",
+    ]),
+    ("Greek letters", &[
+        "Alpha/α", "Beta/β", "Gamma/γ", "Delta/δ", "Epsilon/ε", "Zeta/ζ", "Eta/η", "Theta/θ",
+        "Iota/ι", "Kappa/κ", "Lambda/λ", "Mu/μ", "Nu/ν", "Xi/ξ", "Omicron/ο", "Pi/π", "Rho/ρ",
+        "Sigma/σ", "Tau/τ", "Upsilon/υ", "Phi/φ", "Chi/χ", "Psi/ψ", "Omega/ω",
+    ]),
+    ("Hebrew letters", &[
+        "Aleph/Alef", "Bet/Beth", "Gimel", "Dalet/Daleth", "He", "Vav/Waw", "Zayin", "Chet/Heth",
+        "Tet/Teth", "Yod/Yodh", "Kaf/Kaph", "Lamed/Lamedh", "Mem", "Nun", "Samekh", "Ayin", "Pe",
+        "Tsadi/Tsade", "Qof/Qoph", "Resh", "Shin", "Tav/Taw",
+    ]),
+    ("Chess pieces (by value)", &[
+        "Pawn", "Knight", "Bishop", "Rook", "Queen", "King",
+    ]),
+    ("US presidents", &[
+        "Washington", "Adams", "Jefferson", "Madison", "Monroe", "Adams", "Jackson", "Van Buren",
+        "Harrison", "Tyler", "Polk", "Taylor", "Fillmore", "Pierce", "Buchanan", "Lincoln",
+        "Johnson", "Grant", "Hayes", "Garfield", "Arthur", "Cleveland", "Harrison", "Cleveland",
+        "McKinley", "Roosevelt", "Taft", "Wilson", "Harding", "Coolidge", "Hoover", "Roosevelt",
+        "Truman", "Eisenhower", "Kennedy", "Johnson", "Nixon", "Ford", "Carter", "Reagan", "Bush",
+        "Clinton", "Bush", "Obama", "Trump", "Biden", "Trump",
+    ]),
+    ("Rainbow colors", &[
+        "Red", "Orange", "Yellow", "Green", "Blue", "Indigo", "Violet",
+    ]),
+    ("Solfège", &[
+        "Do/Ut", "Re", "Mi", "Fa", "Sol/So", "La", "Ti/Si",
+    ]),
+    ("Months (English)", &[
+        "January", "February", "March", "April", "May", "June", "July", "August", "September",
+        "October", "November", "December",
+    ]),
+    ("Months (French)", &[
+        "Janvier", "Février", "Mars", "Avril", "Mai", "Juin", "Juillet", "Août", "Septembre",
+        "Octobre", "Novembre", "Décembre",
+    ]),
+    ("Months (Spanish)", &[
+        "Enero", "Febrero", "Marzo", "Abril", "Mayo", "Junio", "Julio", "Agosto", "Septiembre",
+        "Octubre", "Noviembre", "Diciembre",
+    ]),
+    ("Months (German)", &[
+        "Januar", "Februar", "März", "April", "Mai", "Juni", "Juli", "August", "September",
+        "Oktober", "November", "Dezember",
+    ]),
+    ("Months (Italian)", &[
+        "Gennaio", "Febbraio", "Marzo", "Aprile", "Maggio", "Giugno", "Luglio", "Agosto",
+        "Settembre", "Ottobre", "Novembre", "Dicembre",
+    ]),
+    ("Months (Portuguese)", &[
+        "Janeiro", "Fevereiro", "Março", "Abril", "Maio", "Junho", "Julho", "Agosto", "Setembro",
+        "Outubro", "Novembro", "Dezembro",
+    ]),
+    ("Days of the week (English)", &[
+        "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday", "Sunday",
+    ]),
+    ("Days of the week (French)", &[
+        "Lundi", "Mardi", "Mercredi", "Jeudi", "Vendredi", "Samedi", "Dimanche",
+    ]),
+    ("Days of the week (Spanish)", &[
+        "Lunes", "Martes", "Miércoles", "Jueves", "Viernes", "Sábado", "Domingo",
+    ]),
+    ("Days of the week (German)", &[
+        "Montag", "Dienstag", "Mittwoch", "Donnerstag", "Freitag", "Samstag", "Sonntag",
+    ]),
+    ("Numbers (English)", &[
+        "One", "Two", "Three", "Four", "Five", "Six", "Seven", "Eight", "Nine", "Ten", "Eleven",
+        "Twelve",
+    ]),
+    ("Numbers (French)", &[
+        "Un/Une", "Deux", "Trois", "Quatre", "Cinq", "Six", "Sept", "Huit", "Neuf", "Dix", "Onze",
+        "Douze",
+    ]),
+    ("Numbers (Spanish)", &[
+        "Uno/Una", "Dos", "Tres", "Cuatro", "Cinco", "Seis", "Siete", "Ocho", "Nueve", "Diez",
+        "Once", "Doce",
+    ]),
+    ("Numbers (German)", &[
+        "Eins", "Zwei", "Drei", "Vier", "Fünf", "Sechs", "Sieben", "Acht", "Neun", "Zehn", "Elf",
+        "Zwölf",
+    ]),
+];
+
+/// `SETS` plus the sets whose data lives in other tools.
+fn catalog() -> &'static [(&'static str, Vec<&'static str>)] {
+    static CATALOG: OnceLock<Vec<(&str, Vec<&str>)>> = OnceLock::new();
+    CATALOG.get_or_init(|| {
+        let mut catalog = SETS
+            .iter()
+            .map(|&(name, items)| (name, items.to_vec()))
+            .collect::<Vec<_>>();
+        catalog.push(("NATO alphabet", charts::NATO[..ALPHABET_CNT].to_vec()));
+        catalog.push((
+            "Chemical elements",
+            lookup::ELEMENTS.iter().map(|&(_, name)| name).collect(),
+        ));
+        catalog.push((
+            "Element symbols",
+            lookup::ELEMENTS.iter().map(|&(symbol, _)| symbol).collect(),
+        ));
+        catalog
+    })
+}
+
+/// Lowercase letters and digits only, with accents dropped.
+fn normalize(text: &str) -> String {
+    text.chars()
+        .flat_map(char::to_lowercase)
+        .filter(|c| c.is_alphanumeric())
+        .map(|c| match c {
+            'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' => 'a',
+            'ç' => 'c',
+            'è' | 'é' | 'ê' | 'ë' => 'e',
+            'ì' | 'í' | 'î' | 'ï' => 'i',
+            'ñ' => 'n',
+            'ò' | 'ó' | 'ô' | 'õ' | 'ö' => 'o',
+            'ù' | 'ú' | 'û' | 'ü' => 'u',
+            _ => c,
+        })
+        .collect()
+}
+
+/// The 1-based positions of every item in the set that the query could be.
+fn positions(items: &[&str], query: &str) -> Vec<usize> {
+    let query = normalize(query);
+    items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| item.split('/').any(|alias| normalize(alias) == query))
+        .map(|(i, _)| i + 1)
+        .collect()
+}
+
+fn index_letter(index: usize) -> Option<char> {
+    (1..=ALPHABET_CNT)
+        .contains(&index)
+        .then(|| LETTERS.as_bytes()[index - 1] as char)
+}
+
+#[derive(Default)]
+pub struct Sets {
+    pub(super) input: String,
+}
+
+impl Sets {
+    pub fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.label("Items, one per line or separated by commas");
+        ui.text_edit_multiline(&mut self.input);
+        ui.separator();
+
+        let queries = self
+            .input
+            .split(['\n', ','])
+            .map(str::trim)
+            .filter(|query| !query.is_empty())
+            .collect::<Vec<_>>();
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            if queries.is_empty() {
+                browse(ui);
+            } else {
+                search(ui, &queries);
+            }
+        });
+    }
+}
+
+fn browse(ui: &mut egui::Ui) {
+    for (name, items) in catalog() {
+        egui::CollapsingHeader::new(*name).show(ui, |ui| {
+            ui.horizontal_wrapped(|ui| {
+                for (i, item) in items.iter().enumerate() {
+                    ui.label(RichText::new((i + 1).to_string()).weak());
+                    ui.label(*item);
+                }
+            });
+        });
+    }
+}
+
+/// Lists the sets holding the most queries, with where each query sits in them.
+fn search(ui: &mut egui::Ui, queries: &[&str]) {
+    let mut matches = catalog()
+        .iter()
+        .map(|(name, items)| {
+            let found = queries
+                .iter()
+                .map(|query| positions(items, query))
+                .collect::<Vec<_>>();
+            let count = found.iter().filter(|found| !found.is_empty()).count();
+            (count, name, items.len(), found)
+        })
+        .filter(|&(count, ..)| count > 0)
+        .collect::<Vec<_>>();
+    matches.sort_by_key(|&(count, _, len, _)| (std::cmp::Reverse(count), len));
+    if matches.is_empty() {
+        ui.label("No set has any of these items.");
+    }
+
+    for (rank, (count, name, len, found)) in matches.into_iter().take(MAX_MATCHES).enumerate() {
+        let header = format!(
+            "{name}: {count} of {} items ({len} in the set)",
+            queries.len()
+        );
+        egui::CollapsingHeader::new(header)
+            .id_salt(name)
+            .default_open(rank == 0)
+            .show(ui, |ui| {
+                egui::Grid::new(("Set matches", name))
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for (query, positions) in queries.iter().zip(&found) {
+                            ui.label(*query);
+                            if positions.is_empty() {
+                                ui.label(RichText::new("✖").color(ui.visuals().warn_fg_color));
+                            } else {
+                                let positions = positions
+                                    .iter()
+                                    .map(ToString::to_string)
+                                    .collect::<Vec<_>>();
+                                ui.label(positions.join(" or "));
+                            }
+                            ui.end_row();
+                        }
+                    });
+                if count == queries.len() {
+                    let first = found.iter().map(|positions| positions[0]);
+                    let indices = first.clone().map(|i| i.to_string()).collect::<Vec<_>>();
+                    ui.horizontal(|ui| {
+                        ui.label("Indices");
+                        copyable(ui, &indices.join(" "));
+                    });
+                    if let Some(letters) = first.map(index_letter).collect::<Option<String>>() {
+                        ui.horizontal(|ui| {
+                            ui.label("As letters");
+                            copyable(ui, &letters);
+                        });
+                    }
+                }
+            });
+    }
+}
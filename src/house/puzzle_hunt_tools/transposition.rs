@@ -0,0 +1,412 @@
+//! Transposition ciphers: rail fence, keyed columnar, routes through rectangles and skip reading.
+//!
+//! Every transposition here is an order of plaintext positions:
+//! encrypting reads the plaintext in that order, decrypting puts the letters back.
+
+use super::{copyable, english};
+
+const MAX_RAILS: usize = 20;
+const MAX_BRUTE_FORCE_WIDTH: usize = 7;
+const BRUTE_FORCE_RESULTS: usize = 10;
+
+pub fn read(text: &[char], order: &[usize]) -> String {
+    order.iter().map(|&i| text[i]).collect()
+}
+
+pub fn unread(text: &[char], order: &[usize]) -> String {
+    let mut plain = vec![' '; text.len()];
+    for (&c, &i) in text.iter().zip(order) {
+        plain[i] = c;
+    }
+    plain.into_iter().collect()
+}
+
+fn rail_fence(len: usize, rails: usize) -> Vec<usize> {
+    let cycle = 2 * (rails - 1);
+    let rail = |i: usize| (i % cycle).min(cycle - i % cycle);
+    let mut order = (0..len).collect::<Vec<_>>();
+    order.sort_by_key(|&i| rail(i));
+    order
+}
+
+/// The order in which columns are read: alphabetical by key letter, ties left to right.
+pub fn key_order(key: &str) -> Vec<usize> {
+    let key = key
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .map(|c| c.to_ascii_uppercase())
+        .collect::<Vec<_>>();
+    let mut columns = (0..key.len()).collect::<Vec<_>>();
+    columns.sort_by_key(|&column| key[column]);
+    columns
+}
+
+/// Writes the text in rows `columns.len()` wide and reads the columns in the given order.
+pub fn columnar(len: usize, columns: &[usize]) -> Vec<usize> {
+    let width = columns.len();
+    columns
+        .iter()
+        .flat_map(|&column| (column..len).step_by(width))
+        .collect()
+}
+
+/// Every permutation of `0..n`, in lexicographic order.
+fn permutations(n: usize) -> Vec<Vec<usize>> {
+    let mut current = (0..n).collect::<Vec<_>>();
+    let mut all = vec![current.clone()];
+    loop {
+        let Some(i) = (1..n).rev().find(|&i| current[i - 1] < current[i]) else {
+            return all;
+        };
+        let j = (i..n)
+            .rev()
+            .find(|&j| current[i - 1] < current[j])
+            .unwrap_or(i);
+        current.swap(i - 1, j);
+        current[i..].reverse();
+        all.push(current.clone());
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Route {
+    Columns,
+    SnakeRows,
+    SnakeColumns,
+    Spiral,
+    SpiralCounterclockwise,
+}
+
+impl Route {
+    const ALL: [Self; 5] = [
+        Self::Columns,
+        Self::SnakeRows,
+        Self::SnakeColumns,
+        Self::Spiral,
+        Self::SpiralCounterclockwise,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Columns => "columns",
+            Self::SnakeRows => "snaking rows",
+            Self::SnakeColumns => "snaking columns",
+            Self::Spiral => "clockwise spiral",
+            Self::SpiralCounterclockwise => "counterclockwise spiral",
+        }
+    }
+
+    /// Positions of a `rows`-by-`columns` rectangle filled row by row, visited along the route.
+    fn order(self, rows: usize, columns: usize) -> Vec<usize> {
+        let cell = |row: usize, column: usize| row * columns + column;
+        match self {
+            Self::Columns => (0..columns)
+                .flat_map(|column| (0..rows).map(move |row| cell(row, column)))
+                .collect(),
+            Self::SnakeRows => (0..rows)
+                .flat_map(|row| {
+                    (0..columns)
+                        .map(move |i| cell(row, if row % 2 == 0 { i } else { columns - 1 - i }))
+                })
+                .collect(),
+            Self::SnakeColumns => (0..columns)
+                .flat_map(|column| {
+                    (0..rows)
+                        .map(move |i| cell(if column % 2 == 0 { i } else { rows - 1 - i }, column))
+                })
+                .collect(),
+            Self::Spiral => spiral(rows, columns)
+                .into_iter()
+                .map(|(row, column)| cell(row, column))
+                .collect(),
+            Self::SpiralCounterclockwise => spiral(columns, rows)
+                .into_iter()
+                .map(|(column, row)| cell(row, column))
+                .collect(),
+        }
+    }
+}
+
+/// (row, column) pairs of a clockwise spiral from the top left corner.
+fn spiral(rows: usize, columns: usize) -> Vec<(usize, usize)> {
+    let (mut top, mut bottom, mut left, mut right) = (0, rows, 0, columns);
+    let mut cells = Vec::with_capacity(rows * columns);
+    while top < bottom && left < right {
+        cells.extend((left..right).map(|column| (top, column)));
+        cells.extend((top + 1..bottom).map(|row| (row, right - 1)));
+        if top + 1 < bottom {
+            cells.extend((left..right - 1).rev().map(|column| (bottom - 1, column)));
+        }
+        if left + 1 < right {
+            cells.extend((top + 1..bottom - 1).rev().map(|row| (row, left)));
+        }
+        (top, bottom, left, right) = (top + 1, bottom - 1, left + 1, right - 1);
+    }
+    cells
+}
+
+/// Reads every `step`th letter, wrapping around and moving on to the next unread letter when stuck.
+fn skip(len: usize, step: usize) -> Vec<usize> {
+    let mut visited = vec![false; len];
+    let mut order = Vec::with_capacity(len);
+    let mut position = 0;
+    while order.len() < len {
+        if visited[position] {
+            position = visited.iter().position(|&v| !v).unwrap_or(0);
+        }
+        visited[position] = true;
+        order.push(position);
+        position = (position + step) % len;
+    }
+    order
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Mode {
+    RailFence,
+    Columnar,
+    Route,
+    Skip,
+}
+
+pub struct Transposition {
+    mode: Mode,
+    pub(super) input: String,
+    key: String,
+    sort_by_score: bool,
+    max_width: usize,
+    /// Brute force results and the input they were computed for.
+    brute_force: Option<(String, Vec<(String, String)>)>,
+    /// Scored skip results and the text they were computed for.
+    skip: Option<(Vec<char>, Vec<Scored>)>,
+}
+
+impl Default for Transposition {
+    fn default() -> Self {
+        Self {
+            mode: Mode::RailFence,
+            input: String::new(),
+            key: String::new(),
+            sort_by_score: true,
+            max_width: 5,
+            brute_force: None,
+            skip: None,
+        }
+    }
+}
+
+impl Transposition {
+    pub fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut self.mode, Mode::RailFence, "Rail fence");
+            ui.selectable_value(&mut self.mode, Mode::Columnar, "Columnar");
+            ui.selectable_value(&mut self.mode, Mode::Route, "Route");
+            ui.selectable_value(&mut self.mode, Mode::Skip, "Reverse / skip");
+        });
+        ui.label("Text (spaces are ignored)");
+        ui.text_edit_multiline(&mut self.input);
+        ui.checkbox(&mut self.sort_by_score, "Sort by English score");
+        ui.separator();
+
+        let text = self
+            .input
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<Vec<_>>();
+        if text.len() < 2 {
+            return;
+        }
+        match self.mode {
+            Mode::RailFence => self.ui_rail_fence(ui, &text),
+            Mode::Columnar => self.ui_columnar(ui, &text),
+            Mode::Route => self.ui_route(ui, &text),
+            Mode::Skip => self.ui_skip(ui, &text),
+        }
+    }
+
+    fn ui_rail_fence(&self, ui: &mut egui::Ui, text: &[char]) {
+        let mut rows = Vec::new();
+        for rails in 2..=MAX_RAILS.min(text.len() - 1) {
+            let order = rail_fence(text.len(), rails);
+            rows.push((format!("{rails} rails, decrypted"), unread(text, &order)));
+            rows.push((format!("{rails} rails, encrypted"), read(text, &order)));
+        }
+        results(ui, "Rail fence", rows, self.sort_by_score);
+    }
+
+    fn ui_columnar(&mut self, ui: &mut egui::Ui, text: &[char]) {
+        ui.horizontal(|ui| {
+            ui.label("Key");
+            ui.text_edit_singleline(&mut self.key);
+        });
+        let columns = key_order(&self.key);
+        if !columns.is_empty() {
+            let order = columnar(text.len(), &columns);
+            let rows = vec![
+                ("Decrypted".to_owned(), unread(text, &order)),
+                ("Encrypted".to_owned(), read(text, &order)),
+            ];
+            results(ui, "Columnar", rows, false);
+        }
+
+        ui.separator();
+        ui.heading("Unknown key");
+        ui.add(
+            egui::Slider::new(&mut self.max_width, 2..=MAX_BRUTE_FORCE_WIDTH).text("Longest key"),
+        );
+        let input = text.iter().collect::<String>();
+        if ui.button("Try all keys").clicked() {
+            self.brute_force = Some((input.clone(), brute_force(text, self.max_width)));
+        }
+        match &self.brute_force {
+            Some((tried, rows)) if *tried == input => {
+                results(ui, "Columnar brute force", rows.clone(), true);
+            }
+            _ => {}
+        }
+    }
+
+    fn ui_route(&self, ui: &mut egui::Ui, text: &[char]) {
+        let len = text.len();
+        let mut rows = Vec::new();
+        for height in (2..len).filter(|height| len % height == 0) {
+            let width = len / height;
+            for route in Route::ALL {
+                let order = route.order(height, width);
+                let name = route.name();
+                rows.push((format!("{height}×{width}, read {name}"), read(text, &order)));
+                rows.push((
+                    format!("{height}×{width}, written {name}"),
+                    unread(text, &order),
+                ));
+            }
+        }
+        if rows.is_empty() {
+            ui.label("The length is prime, so there are no rectangles to try.");
+        }
+        results(ui, "Route", rows, self.sort_by_score);
+    }
+
+    fn ui_skip(&mut self, ui: &mut egui::Ui, text: &[char]) {
+        if self
+            .skip
+            .as_ref()
+            .is_none_or(|(skipped, _)| skipped != text)
+        {
+            let mut rows = vec![("Reversed".to_owned(), text.iter().rev().collect())];
+            for step in 2..text.len() {
+                rows.push((
+                    format!("Every letter {step} ahead"),
+                    read(text, &skip(text.len(), step)),
+                ));
+            }
+            self.skip = Some((text.to_vec(), scored(rows)));
+        }
+        if let Some((_, rows)) = &self.skip {
+            results_grid(ui, "Skip", rows, self.sort_by_score);
+        }
+    }
+}
+
+/// Decrypts with every key up to `max_width` columns, keeping the most English results.
+fn brute_force(text: &[char], max_width: usize) -> Vec<(String, String)> {
+    let mut results = Vec::new();
+    for width in 2..=max_width.min(text.len()) {
+        for columns in permutations(width) {
+            let plain = unread(text, &columnar(text.len(), &columns));
+            let mut ranks = vec![0; width];
+            for (rank, &column) in columns.iter().enumerate() {
+                ranks[column] = rank + 1;
+            }
+            let key = ranks.iter().map(ToString::to_string).collect::<String>();
+            results.push((english::score(&plain), format!("Key {key}"), plain));
+        }
+    }
+    results.sort_by(|a, b| b.0.total_cmp(&a.0));
+    results
+        .into_iter()
+        .take(BRUTE_FORCE_RESULTS)
+        .map(|(_, name, plain)| (name, plain))
+        .collect()
+}
+
+/// An English score, a label and the text it belongs to.
+type Scored = (f32, String, String);
+
+fn scored(rows: Vec<(String, String)>) -> Vec<Scored> {
+    rows.into_iter()
+        .map(|(label, text)| (english::score(&text), label, text))
+        .collect()
+}
+
+fn results(ui: &mut egui::Ui, name: &str, rows: Vec<(String, String)>, sort_by_score: bool) {
+    results_grid(ui, name, &scored(rows), sort_by_score);
+}
+
+fn results_grid(ui: &mut egui::Ui, name: &str, rows: &[Scored], sort_by_score: bool) {
+    let mut rows = rows.iter().collect::<Vec<_>>();
+    if sort_by_score {
+        rows.sort_by(|a, b| b.0.total_cmp(&a.0));
+    }
+    egui::Grid::new(name).striped(true).show(ui, |ui| {
+        for (score, label, text) in rows {
+            ui.label(label);
+            english::score_bar(ui, *score);
+            copyable(ui, text);
+            ui.end_row();
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLAIN: &str = "WEAREDISCOVEREDFLEEATONCE";
+
+    fn chars(text: &str) -> Vec<char> {
+        text.chars().collect()
+    }
+
+    #[test]
+    fn rail_fence_round_trips() {
+        let order = rail_fence(PLAIN.len(), 3);
+        let encrypted = read(&chars(PLAIN), &order);
+        assert_eq!(encrypted, "WECRLTEERDSOEEFEAOCAIVDEN");
+        assert_eq!(unread(&chars(&encrypted), &order), PLAIN);
+    }
+
+    #[test]
+    fn columnar_round_trips() {
+        let order = columnar(PLAIN.len(), &key_order("ZEBRAS"));
+        let encrypted = read(&chars(PLAIN), &order);
+        assert_eq!(encrypted, "EVLNACDTESEAROFODEECWIREE");
+        assert_eq!(unread(&chars(&encrypted), &order), PLAIN);
+    }
+
+    #[test]
+    fn routes_through_a_square() {
+        let text = chars("ABCDEFGHI");
+        let routes = [
+            (Route::Columns, "ADGBEHCFI"),
+            (Route::SnakeRows, "ABCFEDGHI"),
+            (Route::SnakeColumns, "ADGHEBCFI"),
+            (Route::Spiral, "ABCFIHGDE"),
+            (Route::SpiralCounterclockwise, "ADGHIFCBE"),
+        ];
+        for (route, expected) in routes {
+            assert_eq!(
+                read(&text, &route.order(3, 3)),
+                expected,
+                "{}",
+                route.name()
+            );
+        }
+    }
+
+    #[test]
+    fn skip_visits_every_letter() {
+        assert_eq!(skip(5, 2), [0, 2, 4, 1, 3]);
+        assert_eq!(skip(6, 2), [0, 2, 4, 1, 3, 5]);
+    }
+}
@@ -0,0 +1,333 @@
+//! A pipeline of text transformations, each showing its output, that can be saved as recipes.
+
+use egui::{Id, RichText};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::super::color_emoji;
+use super::super::scratchpad::{result_label, send_button};
+use super::identifier::caesar_shift;
+use super::{ALPHABET_CNT, HouseGadget, LETTERS, a1z26_letter, morse};
+
+const RECIPES_ID: &str = "Text workbench recipes";
+const MAX_EVERY: usize = 99;
+const PARAMETER_WIDTH: f32 = 80.0;
+
+#[derive(Clone, Serialize, Deserialize)]
+enum Step {
+    Reverse,
+    Uppercase,
+    Lowercase,
+    LettersOnly,
+    /// Every `n`th letter, starting from the `start`th, counting from 1.
+    EveryNth {
+        n: usize,
+        start: usize,
+    },
+    Caesar {
+        shift: u8,
+    },
+    A1z26Encode,
+    A1z26Decode,
+    MorseEncode,
+    MorseDecode,
+    Replace {
+        find: String,
+        with: String,
+        regex: bool,
+    },
+    SortLetters,
+}
+
+impl Step {
+    /// One of each step, as offered in the "Add step" menu.
+    fn all() -> [Self; 12] {
+        [
+            Self::Reverse,
+            Self::Uppercase,
+            Self::Lowercase,
+            Self::LettersOnly,
+            Self::EveryNth { n: 2, start: 1 },
+            Self::Caesar { shift: 13 },
+            Self::A1z26Encode,
+            Self::A1z26Decode,
+            Self::MorseEncode,
+            Self::MorseDecode,
+            Self::Replace {
+                find: String::new(),
+                with: String::new(),
+                regex: false,
+            },
+            Self::SortLetters,
+        ]
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Reverse => "Reverse",
+            Self::Uppercase => "Uppercase",
+            Self::Lowercase => "Lowercase",
+            Self::LettersOnly => "Remove non-letters",
+            Self::EveryNth { .. } => "Every Nth letter",
+            Self::Caesar { .. } => "Caesar shift",
+            Self::A1z26Encode => "A1Z26 encode",
+            Self::A1z26Decode => "A1Z26 decode",
+            Self::MorseEncode => "Morse encode",
+            Self::MorseDecode => "Morse decode",
+            Self::Replace { .. } => "Replace",
+            Self::SortLetters => "Sort letters",
+        }
+    }
+
+    fn ui_parameters(&mut self, ui: &mut egui::Ui) {
+        match self {
+            Self::EveryNth { n, start } => {
+                ui.add(
+                    egui::DragValue::new(n)
+                        .range(1..=MAX_EVERY)
+                        .prefix("every "),
+                );
+                ui.add(
+                    egui::DragValue::new(start)
+                        .range(1..=MAX_EVERY)
+                        .prefix("from "),
+                );
+            }
+            Self::Caesar { shift } => {
+                let max = ALPHABET_CNT as u8 - 1;
+                ui.add(egui::DragValue::new(shift).range(0..=max).prefix("ROT"));
+            }
+            Self::Replace { find, with, regex } => {
+                let find = egui::TextEdit::singleline(find)
+                    .hint_text("find")
+                    .desired_width(PARAMETER_WIDTH);
+                ui.add(find);
+                let with = egui::TextEdit::singleline(with)
+                    .hint_text("with")
+                    .desired_width(PARAMETER_WIDTH);
+                ui.add(with);
+                ui.checkbox(regex, "Regex")
+                    .on_hover_text("Use $1 or ${name} in the replacement for captured groups");
+            }
+            _ => {}
+        }
+    }
+
+    fn apply(&self, text: &str) -> Result<String, String> {
+        let letters = || text.chars().filter(|c| c.is_alphabetic());
+        Ok(match self {
+            Self::Reverse => text.chars().rev().collect(),
+            Self::Uppercase => text.to_uppercase(),
+            Self::Lowercase => text.to_lowercase(),
+            Self::LettersOnly => letters().collect(),
+            Self::EveryNth { n, start } => letters()
+                .skip((*start).max(1) - 1)
+                .step_by((*n).max(1))
+                .collect(),
+            Self::Caesar { shift } => caesar_shift(text, *shift),
+            Self::A1z26Encode => a1z26_encode(text),
+            Self::A1z26Decode => a1z26_decode(text),
+            Self::MorseEncode => morse::encode(text),
+            Self::MorseDecode => morse::decode(&morse::normalize(text).ok_or("Not Morse code")?),
+            Self::Replace { find, .. } if find.is_empty() => text.to_owned(),
+            Self::Replace { find, with, regex } if *regex => {
+                let pattern = Regex::new(find).map_err(|err| err.to_string())?;
+                pattern.replace_all(text, with.as_str()).into_owned()
+            }
+            Self::Replace { find, with, .. } => text.replace(find.as_str(), with),
+            Self::SortLetters => {
+                let mut sorted = letters().flat_map(char::to_uppercase).collect::<Vec<_>>();
+                sorted.sort_unstable();
+                sorted.into_iter().collect()
+            }
+        })
+    }
+}
+
+/// Letters as their places in the alphabet, with words separated by `/`.
+fn a1z26_encode(text: &str) -> String {
+    text.split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter_map(|c| LETTERS[..ALPHABET_CNT].find(c.to_ascii_uppercase()))
+                .map(|index| (index + 1).to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" / ")
+}
+
+/// Numbers as letters, reading `/` as a space and keeping anything else.
+fn a1z26_decode(text: &str) -> String {
+    text.split(|c: char| c.is_whitespace() || c == ',' || c == '-')
+        .filter(|token| !token.is_empty())
+        .map(|token| match token.parse() {
+            Ok(num) => a1z26_letter(num).unwrap_or('✖').to_string(),
+            Err(_) if token == "/" => " ".to_owned(),
+            Err(_) => token.to_owned(),
+        })
+        .collect()
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct Recipe {
+    name: String,
+    steps: Vec<Step>,
+}
+
+enum Edit {
+    Swap(usize, usize),
+    Remove(usize),
+}
+
+pub struct TextWorkbench {
+    input: String,
+    steps: Vec<Step>,
+    recipe_name: String,
+}
+
+impl HouseGadget for TextWorkbench {
+    fn new() -> Self {
+        Self {
+            input: String::new(),
+            steps: Vec::new(),
+            recipe_name: String::new(),
+        }
+    }
+
+    fn title(&self) -> String {
+        "Text workbench".to_owned()
+    }
+
+    fn input(&mut self) -> Option<&mut String> {
+        Some(&mut self.input)
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        let recipes_id = Id::new(RECIPES_ID);
+        let mut recipes = ui.ctx().data_mut(|data| {
+            data.get_persisted_mut_or_default::<Vec<Recipe>>(recipes_id)
+                .clone()
+        });
+        self.ui_recipes(ui, &mut recipes);
+        ui.ctx()
+            .data_mut(|data| data.insert_persisted(recipes_id, recipes));
+        ui.separator();
+
+        ui.label("Input");
+        ui.text_edit_multiline(&mut self.input);
+        let output = self.ui_steps(ui);
+        ui.menu_button(color_emoji::button(ui, "➕ Add step"), |ui| {
+            for step in Step::all() {
+                if ui.button(step.name()).clicked() {
+                    self.steps.push(step);
+                    ui.close();
+                }
+            }
+        });
+
+        ui.separator();
+        ui.heading("Output");
+        ui.horizontal(|ui| {
+            result_label(ui, RichText::new(&output).monospace(), &output);
+            send_button(ui, &output);
+        });
+    }
+}
+
+impl TextWorkbench {
+    fn ui_recipes(&mut self, ui: &mut egui::Ui, recipes: &mut Vec<Recipe>) {
+        ui.horizontal_wrapped(|ui| {
+            ui.label("Recipes:");
+            if recipes.is_empty() {
+                ui.weak("none saved yet");
+            }
+            let mut deleted = None;
+            for (i, recipe) in recipes.iter().enumerate() {
+                let steps = recipe.steps.iter().map(Step::name).collect::<Vec<_>>();
+                let response = ui.button(&recipe.name).on_hover_text(steps.join(" → "));
+                if response.clicked() {
+                    self.steps = recipe.steps.clone();
+                    recipe.name.clone_into(&mut self.recipe_name);
+                }
+                response.context_menu(|ui| {
+                    if ui.button("Delete").clicked() {
+                        deleted = Some(i);
+                        ui.close();
+                    }
+                });
+            }
+            if let Some(i) = deleted {
+                recipes.remove(i);
+            }
+        });
+        ui.horizontal(|ui| {
+            let name = egui::TextEdit::singleline(&mut self.recipe_name).hint_text("Recipe name");
+            ui.add(name);
+            let name = self.recipe_name.trim();
+            let can_save = !name.is_empty() && !self.steps.is_empty();
+            if ui
+                .add_enabled(can_save, egui::Button::new("Save recipe"))
+                .clicked()
+            {
+                let recipe = Recipe {
+                    name: name.to_owned(),
+                    steps: self.steps.clone(),
+                };
+                match recipes.iter_mut().find(|saved| saved.name == recipe.name) {
+                    Some(saved) => *saved = recipe,
+                    None => recipes.push(recipe),
+                }
+            }
+        });
+    }
+
+    /// Shows every step with what it turns the text into, and returns the final text.
+    /// A step that fails passes its input on unchanged.
+    fn ui_steps(&mut self, ui: &mut egui::Ui) -> String {
+        let mut text = self.input.clone();
+        let mut edit = None;
+        let count = self.steps.len();
+        for (i, step) in self.steps.iter_mut().enumerate() {
+            ui.group(|ui| {
+                ui.horizontal(|ui| {
+                    ui.strong(format!("{}. {}", i + 1, step.name()));
+                    step.ui_parameters(ui);
+                    if ui.add_enabled(i > 0, egui::Button::new("⏶")).clicked() {
+                        edit = Some(Edit::Swap(i - 1, i));
+                    }
+                    if ui
+                        .add_enabled(i + 1 < count, egui::Button::new("⏷"))
+                        .clicked()
+                    {
+                        edit = Some(Edit::Swap(i, i + 1));
+                    }
+                    if ui.button("✖").on_hover_text("Remove step").clicked() {
+                        edit = Some(Edit::Remove(i));
+                    }
+                });
+                match step.apply(&text) {
+                    Ok(output) => {
+                        result_label(ui, RichText::new(&output).monospace(), &output);
+                        text = output;
+                    }
+                    Err(err) => {
+                        ui.label(
+                            RichText::new(format!("⚠ {err}")).color(ui.visuals().warn_fg_color),
+                        );
+                    }
+                }
+            });
+        }
+        match edit {
+            Some(Edit::Swap(a, b)) => self.steps.swap(a, b),
+            Some(Edit::Remove(i)) => {
+                self.steps.remove(i);
+            }
+            None => {}
+        }
+        text
+    }
+}
@@ -0,0 +1,314 @@
+//! A regex playground: matches and groups highlighted in test text, a replace preview,
+//! and a search through the word list that doubles as a crossword helper.
+
+use egui::text::LayoutJob;
+use egui::{Color32, FontId, RichText, Stroke, TextFormat};
+use regex::{Regex, RegexBuilder};
+
+use crate::house::HouseGadget;
+use crate::house::scratchpad::{result_label, send_button, send_button_with};
+use crate::house::words::words;
+
+const MAX_MATCHES: usize = 200;
+const MAX_WORDS: usize = 500;
+const TEXT_FONT_SIZE: f32 = 14.0;
+const MATCH_COLORS: [Color32; 2] = [
+    Color32::from_rgba_premultiplied(90, 140, 40, 110),
+    Color32::from_rgba_premultiplied(40, 110, 150, 110),
+];
+const GROUP_COLORS: [Color32; 4] = [
+    Color32::from_rgb(230, 120, 30),
+    Color32::from_rgb(200, 60, 160),
+    Color32::from_rgb(60, 160, 220),
+    Color32::from_rgb(220, 200, 40),
+];
+
+#[derive(Clone, Copy, PartialEq)]
+enum Mode {
+    Text,
+    Wordlist,
+}
+
+/// Everything the compiled regex depends on.
+#[derive(Clone, PartialEq)]
+struct Options {
+    pattern: String,
+    case_insensitive: bool,
+    multi_line: bool,
+    dot_all: bool,
+    /// Whether `?` or `.` stands for any letter and `*` for any run of letters.
+    crossword: bool,
+    /// Whether the pattern must match a whole word in the word list.
+    whole_word: bool,
+}
+
+impl Options {
+    fn compile(&self, mode: Mode) -> Result<Regex, String> {
+        // The checkbox is only shown for the word list, so it must not leak into text mode.
+        let crossword = mode == Mode::Wordlist && self.crossword;
+        let mut pattern = if crossword {
+            self.pattern
+                .chars()
+                .map(|c| match c {
+                    '?' | '.' => "[a-z]".to_owned(),
+                    '*' => "[a-z]*".to_owned(),
+                    _ => regex::escape(&c.to_string()),
+                })
+                .collect()
+        } else {
+            self.pattern.clone()
+        };
+        if mode == Mode::Wordlist && (self.whole_word || crossword) {
+            pattern = format!("^(?:{pattern})$");
+        }
+        RegexBuilder::new(&pattern)
+            .case_insensitive(self.case_insensitive || mode == Mode::Wordlist)
+            .multi_line(self.multi_line)
+            .dot_matches_new_line(self.dot_all)
+            .build()
+            .map_err(|err| err.to_string())
+    }
+}
+
+/// A match and its groups, as byte ranges. Groups that took part come with their number.
+struct Found {
+    range: std::ops::Range<usize>,
+    groups: Vec<(usize, std::ops::Range<usize>)>,
+}
+
+fn find(regex: &Regex, text: &str) -> Vec<Found> {
+    regex
+        .captures_iter(text)
+        .take(MAX_MATCHES)
+        .filter_map(|captures| {
+            let range = captures.get(0)?.range();
+            let groups = (1..captures.len())
+                .filter_map(|i| Some((i, captures.get(i)?.range())))
+                .collect();
+            Some(Found { range, groups })
+        })
+        .collect()
+}
+
+/// The test text with matches on colored backgrounds and groups underlined,
+/// the innermost group's color winning.
+fn highlighted(ui: &egui::Ui, text: &str, found: &[Found]) -> LayoutJob {
+    let plain = TextFormat {
+        font_id: FontId::monospace(TEXT_FONT_SIZE),
+        color: ui.visuals().text_color(),
+        ..Default::default()
+    };
+    let mut bounds = vec![0, text.len()];
+    for found in found {
+        bounds.extend([found.range.start, found.range.end]);
+        for (_, group) in &found.groups {
+            bounds.extend([group.start, group.end]);
+        }
+    }
+    bounds.sort_unstable();
+    bounds.dedup();
+
+    let mut job = LayoutJob::default();
+    for segment in bounds.windows(2) {
+        let (start, end) = (segment[0], segment[1]);
+        let mut format = plain.clone();
+        let containing = found
+            .iter()
+            .position(|found| found.range.start <= start && end <= found.range.end);
+        if let Some(i) = containing {
+            format.background = MATCH_COLORS[i % MATCH_COLORS.len()];
+            let group = found[i]
+                .groups
+                .iter()
+                .filter(|(_, group)| group.start <= start && end <= group.end)
+                .max_by_key(|(_, group)| group.start);
+            if let Some((number, _)) = group {
+                let color = GROUP_COLORS[(number - 1) % GROUP_COLORS.len()];
+                format.underline = Stroke::new(2.0, color);
+            }
+        }
+        job.append(&text[start..end], 0.0, format);
+    }
+    job
+}
+
+pub struct RegexTester {
+    input: String,
+    options: Options,
+    replacement: String,
+    mode: Mode,
+    compiled: Option<(Options, Mode, Result<Regex, String>)>,
+    /// Words matching the compiled regex, for the regex they were found with.
+    words: Vec<&'static str>,
+    words_for: Option<(Options, Mode)>,
+}
+
+impl HouseGadget for RegexTester {
+    fn new() -> Self {
+        Self {
+            input: String::new(),
+            options: Options {
+                pattern: String::new(),
+                case_insensitive: false,
+                multi_line: false,
+                dot_all: false,
+                crossword: false,
+                whole_word: true,
+            },
+            replacement: String::new(),
+            mode: Mode::Text,
+            compiled: None,
+            words: Vec::new(),
+            words_for: None,
+        }
+    }
+
+    fn title(&self) -> String {
+        "Regex tester".to_owned()
+    }
+
+    fn input(&mut self) -> Option<&mut String> {
+        Some(&mut self.input)
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.selectable_value(&mut self.mode, Mode::Text, "Test text");
+            ui.selectable_value(&mut self.mode, Mode::Wordlist, "Word list");
+        });
+        ui.separator();
+        self.ui_pattern(ui);
+        let regex = match self.regex() {
+            Ok(regex) => regex.clone(),
+            Err(err) => {
+                let err = RichText::new(format!("⚠ {err}")).color(ui.visuals().warn_fg_color);
+                ui.label(err.monospace());
+                return;
+            }
+        };
+        ui.separator();
+        match self.mode {
+            Mode::Text => self.ui_text(ui, &regex),
+            Mode::Wordlist => self.ui_wordlist(ui, &regex),
+        }
+    }
+}
+
+impl RegexTester {
+    fn ui_pattern(&mut self, ui: &mut egui::Ui) {
+        let options = &mut self.options;
+        let crossword = self.mode == Mode::Wordlist && options.crossword;
+        ui.horizontal(|ui| {
+            ui.label("Pattern");
+            let pattern = egui::TextEdit::singleline(&mut options.pattern)
+                .font(egui::TextStyle::Monospace)
+                .hint_text(if crossword { "c?t*" } else { "regex" });
+            ui.add(pattern);
+        });
+        ui.horizontal_wrapped(|ui| match self.mode {
+            Mode::Text => {
+                ui.checkbox(&mut options.case_insensitive, "Ignore case");
+                ui.checkbox(&mut options.multi_line, "Multi-line")
+                    .on_hover_text("^ and $ match at the start and end of every line");
+                ui.checkbox(&mut options.dot_all, "Dot matches newline");
+            }
+            Mode::Wordlist => {
+                ui.checkbox(&mut options.crossword, "Crossword pattern")
+                    .on_hover_text("? or . is any letter and * is any run of letters");
+                ui.add_enabled(
+                    !options.crossword,
+                    egui::Checkbox::new(&mut options.whole_word, "Whole word"),
+                );
+            }
+        });
+    }
+
+    fn regex(&mut self) -> &Result<Regex, String> {
+        let stale = self
+            .compiled
+            .as_ref()
+            .is_none_or(|(options, mode, _)| *options != self.options || *mode != self.mode);
+        if stale {
+            let regex = self.options.compile(self.mode);
+            self.compiled = Some((self.options.clone(), self.mode, regex));
+        }
+        &self.compiled.as_ref().expect("compiled above").2
+    }
+
+    fn ui_text(&mut self, ui: &mut egui::Ui, regex: &Regex) {
+        ui.label("Test text");
+        ui.text_edit_multiline(&mut self.input);
+        if self.input.is_empty() || self.options.pattern.is_empty() {
+            return;
+        }
+        let found = find(regex, &self.input);
+        let more = if found.len() == MAX_MATCHES { "+" } else { "" };
+        ui.label(format!("{}{more} matches", found.len()));
+        ui.label(highlighted(ui, &self.input, &found));
+
+        if !found.is_empty() {
+            let names = regex.capture_names().collect::<Vec<_>>();
+            egui::Grid::new("Regex matches")
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.strong("#");
+                    ui.strong("Match");
+                    ui.strong("Groups");
+                    ui.end_row();
+                    for (i, found) in found.iter().enumerate() {
+                        ui.label((i + 1).to_string());
+                        let text = &self.input[found.range.clone()];
+                        result_label(ui, RichText::new(text).monospace(), text);
+                        ui.horizontal_wrapped(|ui| {
+                            for (number, group) in &found.groups {
+                                let name = names[*number]
+                                    .map_or_else(|| number.to_string(), str::to_owned);
+                                let color = GROUP_COLORS[(number - 1) % GROUP_COLORS.len()];
+                                ui.label(RichText::new(format!("{name}:")).color(color));
+                                let text = &self.input[group.clone()];
+                                result_label(ui, RichText::new(text).monospace(), text);
+                            }
+                        });
+                        ui.end_row();
+                    }
+                });
+        }
+
+        ui.separator();
+        ui.horizontal(|ui| {
+            ui.label("Replace with");
+            ui.text_edit_singleline(&mut self.replacement)
+                .on_hover_text("Use $1 or ${name} for captured groups");
+        });
+        let replaced = regex.replace_all(&self.input, self.replacement.as_str());
+        ui.horizontal(|ui| {
+            result_label(ui, RichText::new(replaced.as_ref()).monospace(), &replaced);
+            send_button(ui, &replaced);
+        });
+    }
+
+    fn ui_wordlist(&mut self, ui: &mut egui::Ui, regex: &Regex) {
+        if self.options.pattern.is_empty() {
+            ui.label("Search the word list, like ^qu.*z or a crossword pattern like c?t*.");
+            return;
+        }
+        let key = (self.options.clone(), self.mode);
+        if self.words_for.as_ref() != Some(&key) {
+            self.words = words().filter(|word| regex.is_match(word)).collect();
+            self.words_for = Some(key);
+        }
+        let shown = self.words.len().min(MAX_WORDS);
+        ui.horizontal(|ui| {
+            ui.label(format!("{} words, most common first", self.words.len()));
+            send_button_with(ui, || self.words.join(" "));
+        });
+        if shown < self.words.len() {
+            ui.label(format!("Only the first {MAX_WORDS} are shown."));
+        }
+        ui.horizontal_wrapped(|ui| {
+            for word in &self.words[..shown] {
+                result_label(ui, RichText::new(*word).monospace(), word);
+            }
+        });
+    }
+}
@@ -0,0 +1,204 @@
+//! A scratchpad shared by every gadget: results sent here are kept in a history,
+//! can be pinned, and can be fed into the input of another open gadget.
+//!
+//! The history lives in egui's persisted memory, so it outlives the scratchpad window.
+
+use egui::{Id, RichText, Sense};
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator as _;
+
+use crate::house::bus::{self, Message};
+use crate::house::{GadgetKind, HouseGadget, color_emoji};
+
+const HISTORY_ID: &str = "Scratchpad history";
+/// Unpinned entries beyond this many are forgotten, oldest first.
+const MAX_HISTORY: usize = 100;
+const PREVIEW_LEN: usize = 60;
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+struct Entry {
+    text: String,
+    pinned: bool,
+}
+
+/// Entries, newest first.
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct History {
+    entries: Vec<Entry>,
+}
+
+impl History {
+    fn push(&mut self, text: &str) {
+        if text.trim().is_empty() || self.entries.first().is_some_and(|e| e.text == text) {
+            return;
+        }
+        self.entries.insert(
+            0,
+            Entry {
+                text: text.to_owned(),
+                pinned: false,
+            },
+        );
+        let mut unpinned = 0;
+        self.entries.retain(|entry| {
+            unpinned += usize::from(!entry.pinned);
+            entry.pinned || unpinned <= MAX_HISTORY
+        });
+    }
+}
+
+/// Sends `text` to the scratchpad, letting other gadgets know about it too.
+pub fn send(ctx: &egui::Context, text: &str) {
+    let text = text.to_owned();
+    bus::publish(ctx, Message::TextProduced { text });
+}
+
+/// Adds `text` to the top of the scratchpad, unless it is already the newest entry.
+pub fn record(ctx: &egui::Context, text: &str) {
+    ctx.data_mut(|data| {
+        data.get_persisted_mut_or_default::<History>(Id::new(HISTORY_ID))
+            .push(text);
+    });
+}
+
+fn small_emoji_button(ui: &mut egui::Ui, emoji: &str) -> egui::Response {
+    let size = ui.text_style_height(&egui::TextStyle::Body);
+    ui.add(egui::Button::new(color_emoji::atoms(ui.ctx(), emoji, size)).small())
+}
+
+/// A small button that sends `text` to the scratchpad.
+pub fn send_button(ui: &mut egui::Ui, text: &str) {
+    send_button_with(ui, || text.to_owned());
+}
+
+/// Like `send_button`, for text that is only worth building once the button is clicked.
+pub fn send_button_with(ui: &mut egui::Ui, text: impl FnOnce() -> String) {
+    if small_emoji_button(ui, "📝")
+        .on_hover_text("Send to scratchpad")
+        .clicked()
+    {
+        send(ui.ctx(), &text());
+    }
+}
+
+/// A result that is copied when clicked and can be sent to the scratchpad from its context menu.
+pub fn result_label(ui: &mut egui::Ui, text: impl Into<RichText>, result: &str) {
+    let label = egui::Label::new(text.into()).sense(Sense::click());
+    let response = ui
+        .add(label)
+        .on_hover_text("Click to copy, right-click to send to the scratchpad");
+    if response.clicked() {
+        ui.ctx().copy_text(result.to_owned());
+    }
+    response.context_menu(|ui| {
+        if ui.button("Copy").clicked() {
+            ui.ctx().copy_text(result.to_owned());
+            ui.close();
+        }
+        if ui.button("Send to scratchpad").clicked() {
+            send(ui.ctx(), result);
+            ui.close();
+        }
+        ui.menu_button("Open in", |ui| {
+            for kind in GadgetKind::iter().filter(|kind| kind.takes_text()) {
+                if ui.button(kind.name()).clicked() {
+                    let text = result.to_owned();
+                    bus::publish(ui.ctx(), Message::OpenWithInput { kind, text });
+                    ui.close();
+                }
+            }
+        });
+    });
+}
+
+pub struct Scratchpad {
+    note: String,
+}
+
+impl Scratchpad {
+    fn ui_entry(ui: &mut egui::Ui, entry: &mut Entry, targets: &[(Id, String)]) -> bool {
+        let mut keep = true;
+        ui.horizontal(|ui| {
+            let pin = if entry.pinned { "📌" } else { "📍" };
+            if ui
+                .selectable_label(entry.pinned, color_emoji::button(ui, pin))
+                .on_hover_text("Pin")
+                .clicked()
+            {
+                entry.pinned = !entry.pinned;
+            }
+            ui.menu_button(color_emoji::button(ui, "➡"), |ui| {
+                if targets.is_empty() {
+                    ui.label("No open gadget takes input");
+                }
+                for (id, title) in targets {
+                    if ui.button(title).clicked() {
+                        let text = entry.text.clone();
+                        bus::publish(ui.ctx(), Message::SetInput { to: *id, text });
+                        bus::publish(ui.ctx(), Message::RequestFocus(*id));
+                        ui.close();
+                    }
+                }
+            })
+            .response
+            .on_hover_text("Use as input in another gadget");
+            if small_emoji_button(ui, "🗑")
+                .on_hover_text("Delete")
+                .clicked()
+            {
+                keep = false;
+            }
+            let mut preview = entry.text.chars().take(PREVIEW_LEN).collect::<String>();
+            if entry.text.chars().count() > PREVIEW_LEN {
+                preview.push('…');
+            }
+            result_label(ui, RichText::new(preview).monospace(), &entry.text);
+        });
+        keep
+    }
+}
+
+impl HouseGadget for Scratchpad {
+    fn new() -> Self {
+        Self {
+            note: String::new(),
+        }
+    }
+
+    fn title(&self) -> String {
+        "Scratchpad".to_owned()
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        let id = Id::new(HISTORY_ID);
+        let mut history = ui
+            .ctx()
+            .data_mut(|data| data.get_persisted_mut_or_default::<History>(id).clone());
+        let targets = bus::open_gadgets(ui.ctx());
+
+        ui.horizontal(|ui| {
+            let response = ui.text_edit_singleline(&mut self.note);
+            let entered = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            if ui.button("Add").clicked() || entered {
+                history.push(&self.note);
+                self.note.clear();
+            }
+            if ui.button("Clear unpinned").clicked() {
+                history.entries.retain(|entry| entry.pinned);
+            }
+        });
+        ui.separator();
+
+        if history.entries.is_empty() {
+            ui.label("Right-click a result in another gadget to send it here.");
+        }
+        // Pinned entries go first; the sort is stable, so each group stays newest first.
+        history.entries.sort_by_key(|entry| !entry.pinned);
+        history
+            .entries
+            .retain_mut(|entry| Self::ui_entry(ui, entry, &targets));
+
+        ui.ctx().data_mut(|data| data.insert_persisted(id, history));
+    }
+}
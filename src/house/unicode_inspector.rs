@@ -0,0 +1,280 @@
+//! Every code point of a string, with names, categories, blocks and encodings,
+//! and warnings for characters that hide or pass for ASCII.
+
+use egui::RichText;
+use unicode_general_category::{GeneralCategory, get_general_category};
+use unicode_normalization::UnicodeNormalization as _;
+use unicode_segmentation::UnicodeSegmentation as _;
+
+use crate::house::HouseGadget;
+use crate::house::converter::printable_score;
+use crate::house::scratchpad::{result_label, send_button};
+
+const MAX_ROWS: usize = 500;
+/// Hidden messages at least this printable are marked as likely.
+const LIKELY_SCORE: f32 = 0.9;
+
+/// Letters that draw nothing, though their category says otherwise.
+const BLANKS: [char; 5] = ['\u{115F}', '\u{1160}', '\u{3164}', '\u{FFA0}', '\u{2800}'];
+
+/// Letters from other scripts that look like ASCII, and the letter they pass for.
+/// Fullwidth and mathematical letters are caught by NFKC instead.
+#[rustfmt::skip]
+const CONFUSABLES: [(char, char); 52] = [
+    ('а', 'a'), ('е', 'e'), ('о', 'o'), ('р', 'p'), ('с', 'c'), ('у', 'y'), ('х', 'x'),
+    ('і', 'i'), ('ј', 'j'), ('ѕ', 's'), ('ԁ', 'd'), ('ԛ', 'q'), ('ԝ', 'w'), ('һ', 'h'),
+    ('ӏ', 'l'), ('ɡ', 'g'), ('ı', 'i'), ('ν', 'v'), ('ο', 'o'), ('ρ', 'p'), ('ɑ', 'a'),
+    ('А', 'A'), ('В', 'B'), ('Е', 'E'), ('К', 'K'), ('М', 'M'), ('Н', 'H'), ('О', 'O'),
+    ('Р', 'P'), ('С', 'C'), ('Т', 'T'), ('Х', 'X'), ('І', 'I'), ('Ј', 'J'), ('Ѕ', 'S'),
+    ('Ү', 'Y'), ('Α', 'A'), ('Β', 'B'), ('Ε', 'E'), ('Ζ', 'Z'), ('Η', 'H'), ('Ι', 'I'),
+    ('Κ', 'K'), ('Μ', 'M'), ('Ν', 'N'), ('Ο', 'O'), ('Ρ', 'P'), ('Τ', 'T'), ('Υ', 'Y'),
+    ('Χ', 'X'), ('Ԍ', 'G'), ('Ꭵ', 'i'),
+];
+
+fn is_invisible(c: char) -> bool {
+    let variation_selector = matches!(c, '\u{FE00}'..='\u{FE0F}' | '\u{E0100}'..='\u{E01EF}');
+    match get_general_category(c) {
+        GeneralCategory::Control => !matches!(c, '\n' | '\r' | '\t'),
+        GeneralCategory::Format
+        | GeneralCategory::LineSeparator
+        | GeneralCategory::ParagraphSeparator => true,
+        GeneralCategory::SpaceSeparator => c != ' ',
+        _ => variation_selector || BLANKS.contains(&c),
+    }
+}
+
+fn is_mark(c: char) -> bool {
+    matches!(
+        get_general_category(c),
+        GeneralCategory::NonspacingMark
+            | GeneralCategory::SpacingMark
+            | GeneralCategory::EnclosingMark
+    )
+}
+
+/// The ASCII letter or digit that `c` could be mistaken for.
+fn lookalike(c: char) -> Option<char> {
+    if c.is_ascii() {
+        return None;
+    }
+    if let Some(&(_, ascii)) = CONFUSABLES.iter().find(|(other, _)| *other == c) {
+        return Some(ascii);
+    }
+    let mut normalized = std::iter::once(c).nfkc();
+    match (normalized.next(), normalized.next()) {
+        (Some(ascii), None) if ascii.is_ascii_alphanumeric() => Some(ascii),
+        _ => None,
+    }
+}
+
+fn name(c: char) -> String {
+    unicode_names2::name(c).map_or_else(|| "(no name)".to_owned(), |name| name.to_string())
+}
+
+fn utf8(c: char) -> String {
+    let mut buffer = [0; 4];
+    let bytes = c.encode_utf8(&mut buffer).as_bytes();
+    bytes
+        .iter()
+        .map(|b| format!("{b:02X}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn utf16(c: char) -> String {
+    let mut buffer = [0; 2];
+    let units = c.encode_utf16(&mut buffer);
+    units
+        .iter()
+        .map(|u| format!("{u:04X}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Reads the zero-width characters as bits, taking the two most common ones as 0 and 1
+/// both ways round, and skipping any others as separators.
+fn zero_width_messages(text: &str) -> Vec<(String, String)> {
+    let hidden = text
+        .chars()
+        .filter(|&c| get_general_category(c) == GeneralCategory::Format)
+        .collect::<Vec<_>>();
+    let mut kinds = Vec::<(char, usize)>::new();
+    for &c in &hidden {
+        match kinds.iter_mut().find(|(kind, _)| *kind == c) {
+            Some((_, count)) => *count += 1,
+            None => kinds.push((c, 1)),
+        }
+    }
+    kinds.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+    let [(a, _), (b, _), ..] = kinds[..] else {
+        return Vec::new();
+    };
+
+    [(a, b), (b, a)]
+        .into_iter()
+        .map(|(zero, one)| {
+            let bits = hidden
+                .iter()
+                .filter_map(|&c| (c == zero || c == one).then_some(u8::from(c == one)))
+                .collect::<Vec<_>>();
+            let bytes = bits
+                .chunks_exact(8)
+                .map(|byte| byte.iter().fold(0, |acc, bit| acc << 1 | bit))
+                .collect::<Vec<u8>>();
+            let label = format!(
+                "U+{:04X} = 0, U+{:04X} = 1",
+                u32::from(zero),
+                u32::from(one)
+            );
+            (label, String::from_utf8_lossy(&bytes).into_owned())
+        })
+        .collect()
+}
+
+pub struct UnicodeInspector {
+    input: String,
+}
+
+impl HouseGadget for UnicodeInspector {
+    fn new() -> Self {
+        Self {
+            input: String::new(),
+        }
+    }
+
+    fn title(&self) -> String {
+        "Unicode inspector".to_owned()
+    }
+
+    fn input(&mut self) -> Option<&mut String> {
+        Some(&mut self.input)
+    }
+
+    fn ui(&mut self, ui: &mut egui::Ui) {
+        ui.label("Text");
+        ui.text_edit_multiline(&mut self.input);
+        if self.input.is_empty() {
+            return;
+        }
+        ui.label(format!(
+            "{} code points, {} grapheme clusters, {} UTF-8 bytes, {} UTF-16 units",
+            self.input.chars().count(),
+            self.input.graphemes(true).count(),
+            self.input.len(),
+            self.input.encode_utf16().count(),
+        ));
+        self.ui_warnings(ui);
+        ui.separator();
+        self.ui_code_points(ui);
+    }
+}
+
+impl UnicodeInspector {
+    fn ui_warnings(&self, ui: &mut egui::Ui) {
+        let invisible = self.input.chars().filter(|&c| is_invisible(c)).count();
+        let confusable = self.input.chars().filter_map(lookalike).count();
+        if invisible == 0 && confusable == 0 {
+            return;
+        }
+        let warn = ui.visuals().warn_fg_color;
+        if invisible > 0 {
+            let text = format!("⚠ {invisible} invisible characters");
+            ui.label(RichText::new(text).color(warn));
+        }
+        if confusable > 0 {
+            let text = format!("⚠ {confusable} characters that look like ASCII");
+            ui.label(RichText::new(text).color(warn));
+        }
+        let cleaned = self
+            .input
+            .chars()
+            .filter(|&c| !is_invisible(c))
+            .map(|c| lookalike(c).unwrap_or(c))
+            .collect::<String>();
+        ui.horizontal(|ui| {
+            ui.label("Cleaned:");
+            result_label(ui, RichText::new(&cleaned).monospace(), &cleaned);
+            send_button(ui, &cleaned);
+        });
+
+        let messages = zero_width_messages(&self.input);
+        if messages.is_empty() {
+            return;
+        }
+        ui.label("Zero-width characters read as bits:");
+        egui::Grid::new("Zero-width messages")
+            .striped(true)
+            .show(ui, |ui| {
+                for (label, message) in messages {
+                    if printable_score(&message) >= LIKELY_SCORE {
+                        ui.label(RichText::new(format!("★ {label}")).strong());
+                    } else {
+                        ui.label(label);
+                    }
+                    result_label(ui, RichText::new(&message).monospace(), &message);
+                    ui.end_row();
+                }
+            });
+    }
+
+    fn ui_code_points(&self, ui: &mut egui::Ui) {
+        let headings = [
+            "Cluster", "", "Code", "Name", "Category", "Block", "UTF-8", "UTF-16", "",
+        ];
+        let mut rows = 0;
+        let mut truncated = false;
+        egui::Grid::new("Code points").striped(true).show(ui, |ui| {
+            for heading in headings {
+                ui.strong(heading);
+            }
+            ui.end_row();
+            for (cluster, grapheme) in self.input.graphemes(true).enumerate() {
+                for (i, c) in grapheme.chars().enumerate() {
+                    if rows == MAX_ROWS {
+                        truncated = true;
+                        return;
+                    }
+                    rows += 1;
+                    ui.label(if i == 0 {
+                        (cluster + 1).to_string()
+                    } else {
+                        String::new()
+                    });
+                    ui_code_point(ui, c);
+                    ui.end_row();
+                }
+            }
+        });
+        if truncated {
+            ui.label(format!("Only the first {MAX_ROWS} code points are shown."));
+        }
+    }
+}
+
+fn ui_code_point(ui: &mut egui::Ui, c: char) {
+    let glyph = if is_invisible(c) {
+        RichText::new("⬚").weak()
+    } else if is_mark(c) {
+        RichText::new(format!("◌{c}"))
+    } else {
+        RichText::new(c.to_string())
+    };
+    ui.label(glyph.size(20.0));
+    let code = format!("U+{:04X}", u32::from(c));
+    result_label(ui, RichText::new(&code).monospace(), &code);
+    ui.label(name(c));
+    let category = get_general_category(c);
+    ui.label(category.abbreviation())
+        .on_hover_text(format!("{category:?}"));
+    ui.label(unicode_blocks::find_unicode_block(c).map_or("", |block| block.name()));
+    ui.label(RichText::new(utf8(c)).monospace());
+    ui.label(RichText::new(utf16(c)).monospace());
+    let warn = ui.visuals().warn_fg_color;
+    if is_invisible(c) {
+        ui.label(RichText::new("⚠ invisible").color(warn));
+    } else if let Some(ascii) = lookalike(c) {
+        ui.label(RichText::new(format!("⚠ looks like {ascii}")).color(warn));
+    } else {
+        ui.label("");
+    }
+}
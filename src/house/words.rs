@@ -0,0 +1,7 @@
+//! The bundled English word list, most common words first.
+
+const WORD_LIST: &str = include_str!("../../assets/words.txt");
+
+pub fn words() -> impl Iterator<Item = &'static str> {
+    WORD_LIST.lines()
+}